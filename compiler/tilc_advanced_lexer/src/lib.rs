@@ -1,3 +1,5 @@
 mod token_reader;
+mod unicode_chars;
 
 pub use token_reader::*;
+pub use unicode_chars::*;
@@ -1,12 +1,44 @@
-use tilc_ast::{Token, TokenKind};
-use tilc_lexer::Lexer;
+use tilc_ast::{AttributeStyle, CommentKind, Token, TokenKind};
+use tilc_lexer::{
+  Lexer,
+  unescape::{self, Mode},
+};
 use tilc_session::ParseSession;
-use tilc_span::{BytePos, Pos, Span, SpanCtxt, Symbol};
+use tilc_span::{BytePos, KeywordScript, Pos, Span, SpanCtxt, Symbol, kw};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::confusable;
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub struct LosslessToken {
+  pub kind: LosslessTokenKind,
+  pub span: Span,
+}
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum LosslessTokenKind {
+  Whitespace,
+  /// Ordinary comment, doc comments are [`TokenKind::DocComment`] tokens
+  Comment,
+  /// Unknown character that can't be recovered as a token (an error was already reported)
+  Unknown,
+  Token(TokenKind),
+}
+
+enum UnknownCharRecovery {
+  Whitespace,
+  Token(TokenKind),
+}
 
 pub struct TokenReader<'psess, 'lex> {
   pub src: &'lex str,
   pub lexer: Lexer<'lex>,
   pub psess: &'psess ParseSession,
+  /// Script of the keywords of the file, resolved from `#![kilt_sozder]` before lexing
+  pub kw_script: KeywordScript,
 
   pub start_pos: BytePos,
   pub pos: BytePos,
@@ -16,6 +48,7 @@ impl<'psess, 'lex> TokenReader<'psess, 'lex> {
     src: &'lex str,
     lexer: tilc_lexer::Lexer<'lex>,
     psess: &'psess ParseSession,
+    kw_script: KeywordScript,
 
     start_pos: BytePos,
     pos: BytePos,
@@ -24,6 +57,7 @@ impl<'psess, 'lex> TokenReader<'psess, 'lex> {
       src,
       lexer,
       psess,
+      kw_script,
 
       start_pos,
       pos,
@@ -34,95 +68,263 @@ impl<'psess, 'lex> TokenReader<'psess, 'lex> {
     let mut with_whitespace = false;
 
     loop {
-      let token: tilc_lexer::Token = self.lexer.char_to_token();
-      let start: BytePos = self.pos;
-      self.pos = self.pos + BytePos::from_usize(token.len);
+      let token = self.next_lossless_token();
+      match token.kind {
+        LosslessTokenKind::Whitespace => with_whitespace = true,
+        LosslessTokenKind::Comment | LosslessTokenKind::Unknown => {}
+
+        LosslessTokenKind::Token(kind) => {
+          return (
+            Token {
+              kind,
+              span: token.span,
+            },
+            with_whitespace,
+          );
+        }
+      };
+    }
+  }
+  /// Same as [`TokenReader::next_token`], but whitespace, comments and unknown characters
+  /// are returned too, so the spans of the tokens cover the whole source without gaps
+  pub fn next_lossless_token(&mut self) -> LosslessToken {
+    let token: tilc_lexer::Token = self.lexer.char_to_token();
+    let start: BytePos = self.pos;
+    self.pos = self.pos + BytePos::from_usize(token.len);
 
-      let kind: TokenKind = {
-        use tilc_ast::{Delim::*, TokenKind::*};
+    let kind: TokenKind = {
+      use tilc_ast::{Delim::*, TokenKind::*};
 
-        match token.kind {
-          tilc_lexer::TokenKind::Whitespace => {
-            with_whitespace = true;
-            continue;
-          }
-          tilc_lexer::TokenKind::LineComment => continue,
-          tilc_lexer::TokenKind::BlockComment => continue,
+      match token.kind {
+        tilc_lexer::TokenKind::Whitespace => {
+          return self.lossless(LosslessTokenKind::Whitespace, start);
+        }
+        tilc_lexer::TokenKind::LineComment { doc_style } => match doc_style {
+          Some(doc_style) => self.doc_comment(CommentKind::Line, doc_style, start),
+          None => return self.lossless(LosslessTokenKind::Comment, start),
+        },
+        tilc_lexer::TokenKind::BlockComment {
+          doc_style,
+          terminated,
+        } => {
+          if !terminated {
+            let msg = match doc_style {
+              Some(_) => "unterminated block doc-comment",
+              None => "unterminated block comment",
+            };
+            self
+              .psess
+              .dcx()
+              .struct_span_err(self.mk_span(start, start + BytePos::from_usize(2)), msg)
+              .with_note("nested block comments must be closed with their own `*/`")
+              .emit();
+          };
 
-          tilc_lexer::TokenKind::Ident => self.ident(start),
-          tilc_lexer::TokenKind::RawIdent => todo!(),
+          match doc_style {
+            Some(doc_style) => self.doc_comment(CommentKind::Block, doc_style, start),
+            None => return self.lossless(LosslessTokenKind::Comment, start),
+          }
+        }
 
-          tilc_lexer::TokenKind::Literal { kind, suffix_pos } => {
-            let suffix_pos: BytePos = start + BytePos::from_u32(suffix_pos);
-            let (literal_kind, symbol): (tilc_ast::LitKind, Symbol) =
-              self.literal(kind, start, suffix_pos);
+        tilc_lexer::TokenKind::Ident => self.ident(start),
+        tilc_lexer::TokenKind::RawIdent => self.raw_ident(start),
 
-            let suffix = if suffix_pos < self.pos {
-              let str: &str = self.str_from(suffix_pos);
-              if str == "_" {
-                todo!("empty underscore suffix");
-              }
+        tilc_lexer::TokenKind::Literal { kind, suffix_pos } => {
+          let suffix_pos: BytePos = start + BytePos::from_u32(suffix_pos);
+          let (literal_kind, symbol): (tilc_ast::LitKind, Symbol) =
+            self.literal(kind, start, suffix_pos);
 
-              Some(Symbol::intern(str))
-            } else {
+          let suffix = if suffix_pos < self.pos {
+            let str: &str = self.str_from(suffix_pos);
+            if str == "_" {
+              // `"x"_`, the suffix is dropped and the literal is kept
+              let span = self.mk_span(suffix_pos, self.pos);
+              self
+                .psess
+                .dcx()
+                .struct_span_err(span, "bad suffix on literal")
+                .with_label(span, "a suffix can't be just `_`")
+                .emit();
               None
-            };
+            } else {
+              Some(Symbol::intern(str))
+            }
+          } else {
+            None
+          };
 
-            Literal(tilc_ast::Lit {
-              kind: literal_kind,
-              symbol,
-              suffix,
-            })
-          }
+          Literal(tilc_ast::Lit {
+            kind: literal_kind,
+            symbol,
+            suffix,
+          })
+        }
+
+        tilc_lexer::TokenKind::Lifetime => {
+          // The name keeps its `'`, so `'a` never collides with the `a` identifier
+          let lifetime_ident: Symbol = nfc_normalize(self.str_from(start));
+          Lifetime(lifetime_ident, false)
+        }
 
-          tilc_lexer::TokenKind::Lifetime => {
-            let lifetime_ident: Symbol = Symbol::intern(self.str_from(start));
-            todo!();
+        tilc_lexer::TokenKind::Semicolon => Semicolon,
+        tilc_lexer::TokenKind::Colon => Colon,
+        tilc_lexer::TokenKind::Comma => Comma,
+        tilc_lexer::TokenKind::Dot => Dot,
+        tilc_lexer::TokenKind::OpenParen => OpenDelim(Paren),
+        tilc_lexer::TokenKind::CloseParen => CloseDelim(Paren),
+        tilc_lexer::TokenKind::OpenBrace => OpenDelim(Brace),
+        tilc_lexer::TokenKind::CloseBrace => CloseDelim(Brace),
+        tilc_lexer::TokenKind::OpenBracket => OpenDelim(Bracket),
+        tilc_lexer::TokenKind::CloseBracket => CloseDelim(Bracket),
+        tilc_lexer::TokenKind::At => At,
+        tilc_lexer::TokenKind::Hashtag => Hashtag,
+        tilc_lexer::TokenKind::Tilde => Tilde,
+        tilc_lexer::TokenKind::Question => Question,
+        tilc_lexer::TokenKind::Dollar => Dollar,
+        tilc_lexer::TokenKind::Eq => Eq,
+        tilc_lexer::TokenKind::Bang => Bang,
+        tilc_lexer::TokenKind::Lt => Lt,
+        tilc_lexer::TokenKind::Gt => Gt,
+        tilc_lexer::TokenKind::Minus => BinOp(tilc_ast::BinOp::Minus),
+        tilc_lexer::TokenKind::Plus => BinOp(tilc_ast::BinOp::Plus),
+        tilc_lexer::TokenKind::And => BinOp(tilc_ast::BinOp::And),
+        tilc_lexer::TokenKind::Or => BinOp(tilc_ast::BinOp::Or),
+        tilc_lexer::TokenKind::Star => BinOp(tilc_ast::BinOp::Star),
+        tilc_lexer::TokenKind::Slash => BinOp(tilc_ast::BinOp::Slash),
+        tilc_lexer::TokenKind::Caret => BinOp(tilc_ast::BinOp::Caret),
+        tilc_lexer::TokenKind::Percent => BinOp(tilc_ast::BinOp::Percent),
+        tilc_lexer::TokenKind::Unknown => match self.unknown_char(start) {
+          Some(UnknownCharRecovery::Whitespace) => {
+            return self.lossless(LosslessTokenKind::Whitespace, start);
           }
+          Some(UnknownCharRecovery::Token(kind)) => kind,
+          None => return self.lossless(LosslessTokenKind::Unknown, start),
+        },
 
-          tilc_lexer::TokenKind::Semicolon => Semicolon,
-          tilc_lexer::TokenKind::Colon => Colon,
-          tilc_lexer::TokenKind::Comma => Comma,
-          tilc_lexer::TokenKind::Dot => Dot,
-          tilc_lexer::TokenKind::OpenParen => OpenDelim(Paren),
-          tilc_lexer::TokenKind::CloseParen => CloseDelim(Paren),
-          tilc_lexer::TokenKind::OpenBrace => OpenDelim(Brace),
-          tilc_lexer::TokenKind::CloseBrace => CloseDelim(Brace),
-          tilc_lexer::TokenKind::OpenBracket => OpenDelim(Bracket),
-          tilc_lexer::TokenKind::CloseBracket => CloseDelim(Bracket),
-          tilc_lexer::TokenKind::At => At,
-          tilc_lexer::TokenKind::Hashtag => Hashtag,
-          tilc_lexer::TokenKind::Tilde => Tilde,
-          tilc_lexer::TokenKind::Question => Question,
-          tilc_lexer::TokenKind::Dollar => Dollar,
-          tilc_lexer::TokenKind::Eq => Eq,
-          tilc_lexer::TokenKind::Bang => Bang,
-          tilc_lexer::TokenKind::Lt => Lt,
-          tilc_lexer::TokenKind::Gt => Gt,
-          tilc_lexer::TokenKind::Minus => BinOp(tilc_ast::BinOp::Minus),
-          tilc_lexer::TokenKind::Plus => BinOp(tilc_ast::BinOp::Plus),
-          tilc_lexer::TokenKind::And => BinOp(tilc_ast::BinOp::And),
-          tilc_lexer::TokenKind::Or => BinOp(tilc_ast::BinOp::Or),
-          tilc_lexer::TokenKind::Star => BinOp(tilc_ast::BinOp::Star),
-          tilc_lexer::TokenKind::Slash => BinOp(tilc_ast::BinOp::Slash),
-          tilc_lexer::TokenKind::Caret => BinOp(tilc_ast::BinOp::Caret),
-          tilc_lexer::TokenKind::Percent => BinOp(tilc_ast::BinOp::Percent),
-          tilc_lexer::TokenKind::Unknown => todo!(),
-
-          tilc_lexer::TokenKind::Eof => Eof,
-        }
-      };
+        tilc_lexer::TokenKind::Eof => Eof,
+      }
+    };
 
-      let span = self.mk_span(start, self.pos);
-      return (Token { kind, span }, with_whitespace);
+    return self.lossless(LosslessTokenKind::Token(kind), start);
+  }
+  fn lossless(&self, kind: LosslessTokenKind, start: BytePos) -> LosslessToken {
+    LosslessToken {
+      kind,
+      span: self.mk_span(start, self.pos),
     }
   }
+  /// Reports the unknown character, and returns the token it was probably meant as
+  /// (so lexing continues as if the user wrote the right character)
+  fn unknown_char(&self, start: BytePos) -> Option<UnknownCharRecovery> {
+    let text = self.str_from(start);
+    let ch = text.chars().next().unwrap_or_else(|| unreachable!());
+    let span = self.mk_span(start, self.pos);
+
+    let mut diag = self.psess.dcx().struct_span_err(
+      span,
+      format!("unknown start of token: {}", ch.escape_debug()),
+    );
+    let Some(confusable) = confusable(ch) else {
+      if ch == '\u{200B}' {
+        diag = diag.with_help("invisible characters like '\\u{200b}' are not allowed");
+      };
+      diag.emit();
+      return None;
+    };
+
+    diag
+      .with_suggestion(
+        span,
+        format!(
+          "Unicode character '{}' ({}) looks like '{}' ({}), but it is not",
+          ch.escape_debug(),
+          confusable.name,
+          confusable.ascii,
+          confusable.ascii_name
+        ),
+        confusable.ascii.to_string(),
+      )
+      .emit();
+
+    use tilc_ast::{BinOp, Delim::*, TokenKind::*};
+    let kind = match confusable.ascii {
+      ' ' => return Some(UnknownCharRecovery::Whitespace),
+
+      ';' => Semicolon,
+      ':' => Colon,
+      ',' => Comma,
+      '.' => Dot,
+      '!' => Bang,
+      '?' => Question,
+      '(' => OpenDelim(Paren),
+      ')' => CloseDelim(Paren),
+      '[' => OpenDelim(Bracket),
+      ']' => CloseDelim(Bracket),
+      '{' => OpenDelim(Brace),
+      '}' => CloseDelim(Brace),
+      '<' => Lt,
+      '>' => Gt,
+      '=' => Eq,
+      '+' => BinOp(BinOp::Plus),
+      '-' => BinOp(BinOp::Minus),
+      '*' => BinOp(BinOp::Star),
+      '/' => BinOp(BinOp::Slash),
+      '%' => BinOp(BinOp::Percent),
+      '&' => BinOp(BinOp::And),
+      '|' => BinOp(BinOp::Or),
+      '^' => BinOp(BinOp::Caret),
+      '#' => Hashtag,
+      '@' => At,
+      '~' => Tilde,
+
+      // Quotes can't be recovered without re-lexing the literal
+      _ => return None,
+    };
+    return Some(UnknownCharRecovery::Token(kind));
+  }
+  fn doc_comment(
+    &self,
+    comment_kind: CommentKind,
+    doc_style: tilc_lexer::DocStyle,
+    start: BytePos,
+  ) -> TokenKind {
+    let style = match doc_style {
+      tilc_lexer::DocStyle::Outer => AttributeStyle::Outer,
+      tilc_lexer::DocStyle::Inner => AttributeStyle::Inner,
+    };
+
+    // Skip `///`, `//!`, `/**` or `/*!`
+    let content = &self.str_from(start)[3..];
+    let content = match comment_kind {
+      CommentKind::Line => content.strip_suffix('\r').unwrap_or(content),
+      CommentKind::Block => content.strip_suffix("*/").unwrap_or(content),
+    };
+
+    TokenKind::DocComment(comment_kind, style, Symbol::intern(content))
+  }
   fn ident(&self, start: BytePos) -> TokenKind {
-    let symbol = Symbol::intern(self.str_from(start));
+    let symbol = nfc_normalize(self.str_from(start));
+
+    if let Some(kw @ (kw::True | kw::False)) = symbol.kw_from(self.kw_script) {
+      return TokenKind::Literal(tilc_ast::Lit {
+        kind: tilc_ast::LitKind::Bool,
+        symbol: kw,
+        suffix: None,
+      });
+    };
+
     let span = self.mk_span(start, self.pos);
     self.psess.symbol_repo.insert(symbol, span);
     TokenKind::Ident(symbol, false)
   }
+  fn raw_ident(&self, start: BytePos) -> TokenKind {
+    // Skip `s#`
+    let name_start = start + BytePos::from_usize(2);
+    let symbol = nfc_normalize(self.str_from(name_start));
+    let span = self.mk_span(name_start, self.pos);
+    self.psess.symbol_repo.insert(symbol, span);
+    TokenKind::Ident(symbol, true)
+  }
   fn literal(
     &self,
     literal_kind: tilc_lexer::LiteralKind,
@@ -130,40 +332,253 @@ impl<'psess, 'lex> TokenReader<'psess, 'lex> {
     suffix_pos: BytePos,
   ) -> (tilc_ast::LitKind, Symbol) {
     return match literal_kind {
-      tilc_lexer::LiteralKind::Int { base } => {
+      tilc_lexer::LiteralKind::Int { base, empty_int } => {
         let kind: tilc_ast::LitKind = tilc_ast::LitKind::Int;
-        match base {
-          tilc_lexer::Base::Decimal => {}
-
-          _ => unimplemented!(),
+        if empty_int {
+          self.psess.dcx().emit_err(
+            self.mk_span(start, self.pos),
+            "no valid digits found for number",
+          );
+        } else if matches!(base, tilc_lexer::Base::Binary | tilc_lexer::Base::Octal) {
+          self.check_digits(base, start + BytePos::from_u32(2), suffix_pos);
+        } else if matches!(base, tilc_lexer::Base::Hexadecimal) && suffix_pos == self.pos {
+          self.check_absorbed_suffix(start);
         };
 
         (kind, self.symbol_from_to(start, suffix_pos))
       }
-      tilc_lexer::LiteralKind::Float { base } => {
+      tilc_lexer::LiteralKind::Float {
+        base,
+        empty_exponent,
+      } => {
         let kind: tilc_ast::LitKind = tilc_ast::LitKind::Float;
+        if empty_exponent {
+          self.psess.dcx().emit_err(
+            self.mk_span(start, self.pos),
+            "expected at least one digit in exponent",
+          );
+        };
+
         let base: Option<&str> = match base {
           tilc_lexer::Base::Hexadecimal => Some("hexadecimal"),
-          tilc_lexer::Base::Octal => Some("ocatl"),
+          tilc_lexer::Base::Octal => Some("octal"),
           tilc_lexer::Base::Binary => Some("binary"),
 
           _ => None,
         };
         if let Some(base) = base {
-          panic!("Unsopported base {} for float number", base);
+          self
+            .psess
+            .dcx()
+            .struct_span_err(
+              self.mk_span(start, suffix_pos),
+              format!("{} float literal is not supported", base),
+            )
+            .with_help("only decimal float literals are allowed")
+            .emit();
         };
 
         (kind, self.symbol_from_to(start, suffix_pos))
       }
 
-      tilc_lexer::LiteralKind::Str => {
-        let kind = tilc_ast::LitKind::Str;
+      tilc_lexer::LiteralKind::Char { terminated } => {
+        if !terminated {
+          self.report_unterminated(start, "unterminated character literal");
+        };
 
-        (kind, self.symbol_from_to(start, suffix_pos))
+        let symbol = self.cook_quoted(Mode::Char, start, suffix_pos, 1, terminated);
+        (tilc_ast::LitKind::Char, symbol)
+      }
+      tilc_lexer::LiteralKind::Byte { terminated } => {
+        if !terminated {
+          self.report_unterminated(start, "unterminated byte constant");
+        };
+
+        let symbol = self.cook_quoted(Mode::Byte, start, suffix_pos, 2, terminated);
+        (tilc_ast::LitKind::Byte, symbol)
+      }
+      tilc_lexer::LiteralKind::Str { terminated } => {
+        if !terminated {
+          self.report_unterminated(start, "unterminated double quote string");
+        };
+
+        let symbol = self.cook_quoted(Mode::Str, start, suffix_pos, 1, terminated);
+        (tilc_ast::LitKind::Str, symbol)
+      }
+      tilc_lexer::LiteralKind::ByteStr { terminated } => {
+        if !terminated {
+          self.report_unterminated(start, "unterminated double quote byte string");
+        };
+
+        let symbol = self.cook_quoted(Mode::ByteStr, start, suffix_pos, 2, terminated);
+        (tilc_ast::LitKind::ByteStr, symbol)
+      }
+      tilc_lexer::LiteralKind::RawStr { n_hashes, err } => {
+        if let Some(err) = err {
+          self.report_raw_str_error(start, n_hashes, err);
+          return (
+            tilc_ast::LitKind::Err,
+            self.symbol_from_to(start, suffix_pos),
+          );
+        };
+
+        // The content is taken as is, without unescaping
+        let prefix_len = BytePos::from_u32(2 + n_hashes);
+        let postfix_len = BytePos::from_u32(1 + n_hashes);
+        let symbol = self.symbol_from_to(start + prefix_len, suffix_pos - postfix_len);
+        (tilc_ast::LitKind::RawStr, symbol)
+      }
+    };
+  }
+  fn report_raw_str_error(&self, start: BytePos, n_hashes: u32, err: tilc_lexer::RawStrError) {
+    match err {
+      tilc_lexer::RawStrError::InvalidStarter { bad_char } => {
+        let lo = start + BytePos::from_u32(1 + n_hashes);
+        let hi = lo + BytePos::from_usize(bad_char.len_utf8());
+        self
+          .psess
+          .dcx()
+          .struct_span_err(
+            self.mk_span(lo, hi),
+            format!(
+              "found invalid character; only `#` is allowed in raw string delimitation: {}",
+              bad_char.escape_debug()
+            ),
+          )
+          .emit();
       }
+      tilc_lexer::RawStrError::NoTerminator {
+        expected,
+        found,
+        possible_terminator_offset,
+      } => {
+        let opening = self.mk_span(start, start + BytePos::from_u32(2 + n_hashes));
+        let terminator = format!("\"{}", "#".repeat(expected as usize));
+        let mut diag = self
+          .psess
+          .dcx()
+          .struct_span_err(opening, "unterminated raw string")
+          .with_label(opening, "unterminated raw string")
+          .with_note(format!(
+            "this raw string should be terminated with `{terminator}`"
+          ));
 
-      _ => todo!(),
+        if let Some(offset) = possible_terminator_offset {
+          let lo = start + BytePos::from_u32(offset);
+          let hi = lo + BytePos::from_u32(1 + found);
+          diag = diag
+            .with_label(
+              self.mk_span(lo, hi),
+              format!("expected {expected} `#`, found {found}"),
+            )
+            .with_suggestion(
+              self.mk_span(lo, hi),
+              "consider terminating the string here",
+              terminator,
+            );
+        };
+        diag.emit();
+      }
+      tilc_lexer::RawStrError::TooManyDelimiters { found } => {
+        let hashes = start + BytePos::from_u32(1);
+        self
+          .psess
+          .dcx()
+          .emit_err(
+            self.mk_span(hashes, hashes + BytePos::from_u32(found)),
+            format!(
+              "too many `#` symbols: raw strings may be delimited by up to 255 `#` symbols, but found {found}"
+            ),
+          );
+      }
+    };
+  }
+  /// `b` is a hexadecimal digit, so `0oFFb8` is `0oFFB8` without a suffix
+  fn check_absorbed_suffix(&self, start: BytePos) {
+    let text = self.str_from(start);
+    let Some(suffix) = ["b128", "b16", "b32", "b64", "b8"]
+      .into_iter()
+      .find(|suffix| text.len() > suffix.len() + 2 && text.ends_with(suffix))
+    else {
+      return;
     };
+
+    let suffix_span = self.mk_span(self.pos - BytePos::from_usize(suffix.len()), self.pos);
+    self
+      .psess
+      .dcx()
+      .struct_span_warn(
+        suffix_span,
+        format!("`{suffix}` is read as hexadecimal digits, not as a suffix"),
+      )
+      .with_suggestion(
+        suffix_span,
+        "separate the suffix with `_`",
+        format!("_{suffix}"),
+      )
+      .emit();
+  }
+  /// The lexer consumes all decimal digits for every base,
+  /// so digits that do not fit the base are reported here
+  fn check_digits(&self, base: tilc_lexer::Base, start: BytePos, end: BytePos) {
+    let radix = base.radix();
+    for (idx, ch) in self.str_from_to(start, end).char_indices() {
+      if ch != '_' && ch.to_digit(10).is_some_and(|digit| digit >= radix) {
+        let lo = start + BytePos::from_usize(idx);
+        self.psess.dcx().emit_err(
+          self.mk_span(lo, lo + BytePos::from_u32(1)),
+          format!("invalid digit for a base {} literal", radix),
+        );
+      };
+    }
+  }
+  /// Unescapes the contents of a quoted literal and interns the result
+  ///
+  /// Byte literals are interned with every byte stored as a `char` in `0..=255`.
+  /// If the contents contain invalid escapes, errors are reported and the contents
+  /// are interned as written in the source
+  fn cook_quoted(
+    &self,
+    mode: Mode,
+    start: BytePos,
+    end: BytePos,
+    prefix_len: u32,
+    terminated: bool,
+  ) -> Symbol {
+    let content_start = start + BytePos::from_u32(prefix_len);
+    let content_end = if terminated {
+      end - BytePos::from_u32(1)
+    } else {
+      end
+    };
+    let content = self.str_from_to(content_start, content_end);
+
+    let mut cooked = String::with_capacity(content.len());
+    let mut has_errors = false;
+    unescape::unescape(content, mode, &mut |range, result| match result {
+      Ok(ch) => cooked.push(ch),
+      Err(err) => {
+        has_errors = true;
+        let lo = content_start + BytePos::from_usize(range.start);
+        let hi = content_start + BytePos::from_usize(range.end);
+        self
+          .psess
+          .dcx()
+          .emit_err(self.mk_span(lo, hi), err.message());
+      }
+    });
+
+    if has_errors {
+      Symbol::intern(content)
+    } else {
+      Symbol::intern(&cooked)
+    }
+  }
+  fn report_unterminated(&self, start: BytePos, message: &str) {
+    self
+      .psess
+      .dcx()
+      .emit_err(self.mk_span(start, self.pos), message);
   }
 
   fn str_from_to(&self, start: BytePos, end: BytePos) -> &str {
@@ -183,3 +598,76 @@ impl<'psess, 'lex> TokenReader<'psess, 'lex> {
     Span::new(lo, hi, SpanCtxt::ROOT, None)
   }
 }
+
+/// Identifiers that look the same must be the same symbol,
+/// so non-ASCII identifiers are interned in NFC form
+pub fn nfc_normalize(str: &str) -> Symbol {
+  if str.is_ascii() {
+    return Symbol::intern(str);
+  };
+
+  Symbol::intern(&str.nfc().collect::<String>())
+}
+
+#[cfg(test)]
+mod tests {
+  use tilc_span::with_session_globals;
+
+  use super::*;
+
+  #[test]
+  fn lossless_roundtrip() {
+    let src = "//! Sandyq\n#![kilt_sozder = \"latyn\"]\n\n/// Basty\nfx basty() -> b32                {\r\n\t/* a /* b */ */ ainymaly x = s#\"q\"# + 'a' + 0o1F;\u{a0}\n                 ainymaly ә = &'b ~ 😀 x；\n  qaitar 1.5e3q32 // end\n}";
+
+    let source_map = with_session_globals(|session_globals| session_globals.source_map());
+    let psess = ParseSession::new(source_map);
+    let mut token_reader = TokenReader::new(
+      src,
+      Lexer::new(src),
+      &psess,
+      psess.edition().keyword_script(),
+      BytePos::from_u32(0),
+      BytePos::from_u32(0),
+    );
+
+    let mut roundtrip = String::new();
+    let mut hi = BytePos::from_u32(0);
+    loop {
+      let token = token_reader.next_lossless_token();
+      if token.kind == LosslessTokenKind::Token(TokenKind::Eof) {
+        break;
+      };
+
+      assert_eq!(token.span.lo(), hi, "{token:?}");
+      hi = token.span.hi();
+      roundtrip.push_str(token_reader.str_from_to(token.span.lo(), token.span.hi()));
+    }
+
+    assert_eq!(roundtrip, src);
+  }
+
+  #[test]
+  fn underscore_suffix() {
+    let src = "\"x\"_ 1";
+
+    let source_map = with_session_globals(|session_globals| session_globals.source_map());
+    let psess = ParseSession::new(source_map);
+    let mut token_reader = TokenReader::new(
+      src,
+      Lexer::new(src),
+      &psess,
+      psess.edition().keyword_script(),
+      BytePos::from_u32(0),
+      BytePos::from_u32(0),
+    );
+
+    let (token, _) = token_reader.next_token();
+    assert!(matches!(
+      token.kind,
+      TokenKind::Literal(tilc_ast::Lit { suffix: None, .. })
+    ));
+    let (token, _) = token_reader.next_token();
+    assert!(matches!(token.kind, TokenKind::Literal(_)));
+    assert_eq!(psess.dcx().err_count(), 1);
+  }
+}
@@ -0,0 +1,132 @@
+//! Unicode characters that look like ASCII punctuation, used to suggest
+//! the intended character for unknown tokens
+
+/// (look-alike, its name, ASCII character)
+#[rustfmt::skip]
+const UNICODE_ARRAY: &[(char, &str, char)] = &[
+  ('\u{00A0}', "No-Break Space", ' '),
+  ('\u{2002}', "En Space", ' '),
+  ('\u{2003}', "Em Space", ' '),
+  ('\u{2009}', "Thin Space", ' '),
+  ('\u{3000}', "Ideographic Space", ' '),
+
+  ('；', "Fullwidth Semicolon", ';'),
+  ('\u{037E}', "Greek Question Mark", ';'),
+  ('：', "Fullwidth Colon", ':'),
+  ('，', "Fullwidth Comma", ','),
+  ('、', "Ideographic Comma", ','),
+  ('‚', "Single Low-9 Quotation Mark", ','),
+  ('．', "Fullwidth Full Stop", '.'),
+  ('。', "Ideographic Full Stop", '.'),
+  ('！', "Fullwidth Exclamation Mark", '!'),
+  ('？', "Fullwidth Question Mark", '?'),
+
+  ('（', "Fullwidth Left Parenthesis", '('),
+  ('）', "Fullwidth Right Parenthesis", ')'),
+  ('［', "Fullwidth Left Square Bracket", '['),
+  ('］', "Fullwidth Right Square Bracket", ']'),
+  ('｛', "Fullwidth Left Curly Bracket", '{'),
+  ('｝', "Fullwidth Right Curly Bracket", '}'),
+  ('＜', "Fullwidth Less-Than Sign", '<'),
+  ('‹', "Single Left-Pointing Angle Quotation Mark", '<'),
+  ('＞', "Fullwidth Greater-Than Sign", '>'),
+  ('›', "Single Right-Pointing Angle Quotation Mark", '>'),
+
+  ('＝', "Fullwidth Equals Sign", '='),
+  ('＋', "Fullwidth Plus Sign", '+'),
+  ('−', "Minus Sign", '-'),
+  ('–', "En Dash", '-'),
+  ('—', "Em Dash", '-'),
+  ('－', "Fullwidth Hyphen-Minus", '-'),
+  ('＊', "Fullwidth Asterisk", '*'),
+  ('×', "Multiplication Sign", '*'),
+  ('／', "Fullwidth Solidus", '/'),
+  ('÷', "Division Sign", '/'),
+  ('％', "Fullwidth Percent Sign", '%'),
+  ('＆', "Fullwidth Ampersand", '&'),
+  ('｜', "Fullwidth Vertical Line", '|'),
+  ('＃', "Fullwidth Number Sign", '#'),
+  ('＠', "Fullwidth Commercial At", '@'),
+  ('～', "Fullwidth Tilde", '~'),
+  ('＾', "Fullwidth Circumflex Accent", '^'),
+
+  ('“', "Left Double Quotation Mark", '"'),
+  ('”', "Right Double Quotation Mark", '"'),
+  ('«', "Left-Pointing Double Angle Quotation Mark", '"'),
+  ('»', "Right-Pointing Double Angle Quotation Mark", '"'),
+  ('＂', "Fullwidth Quotation Mark", '"'),
+  ('‘', "Left Single Quotation Mark", '\''),
+  ('’', "Right Single Quotation Mark", '\''),
+  ('＇', "Fullwidth Apostrophe", '\''),
+  ('`', "Grave Accent", '\''),
+];
+
+/// Names of the ASCII characters from [`UNICODE_ARRAY`]
+const ASCII_ARRAY: &[(char, &str)] = &[
+  (' ', "Space"),
+  (';', "Semicolon"),
+  (':', "Colon"),
+  (',', "Comma"),
+  ('.', "Period"),
+  ('!', "Exclamation Mark"),
+  ('?', "Question Mark"),
+  ('(', "Left Parenthesis"),
+  (')', "Right Parenthesis"),
+  ('[', "Left Square Bracket"),
+  (']', "Right Square Bracket"),
+  ('{', "Left Curly Brace"),
+  ('}', "Right Curly Brace"),
+  ('<', "Less-Than Sign"),
+  ('>', "Greater-Than Sign"),
+  ('=', "Equals Sign"),
+  ('+', "Plus Sign"),
+  ('-', "Minus/Hyphen"),
+  ('*', "Asterisk"),
+  ('/', "Slash"),
+  ('%', "Percent Sign"),
+  ('&', "Ampersand"),
+  ('|', "Vertical Bar"),
+  ('#', "Number Sign"),
+  ('@', "At Sign"),
+  ('~', "Tilde"),
+  ('^', "Caret"),
+  ('"', "Quotation Mark"),
+  ('\'', "Single Quote"),
+];
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+pub struct Confusable {
+  pub name: &'static str,
+  pub ascii: char,
+  pub ascii_name: &'static str,
+}
+
+/// ASCII character that `ch` might have been meant as
+pub fn confusable(ch: char) -> Option<Confusable> {
+  let &(_, name, ascii) = UNICODE_ARRAY.iter().find(|&&(unicode, ..)| unicode == ch)?;
+  let &(_, ascii_name) = ASCII_ARRAY
+    .iter()
+    .find(|&&(c, _)| c == ascii)
+    .unwrap_or_else(|| unreachable!());
+
+  return Some(Confusable {
+    name,
+    ascii,
+    ascii_name,
+  });
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn every_ascii_char_has_a_name() {
+    for &(unicode, ..) in UNICODE_ARRAY {
+      assert!(confusable(unicode).is_some(), "{unicode}");
+    }
+    assert_eq!(confusable('；').map(|c| c.ascii), Some(';'));
+    assert!(confusable('ә').is_none());
+  }
+}
@@ -1,7 +1,10 @@
+mod literal;
 mod sandyq;
 mod token;
 mod token_stream;
+pub mod visit;
 
+pub use literal::*;
 pub use sandyq::*;
 pub use token::*;
 pub use token_stream::*;
@@ -0,0 +1,252 @@
+use std::{fmt, num::IntErrorKind, rc::Rc};
+
+use tilc_span::{Symbol, kw, sym};
+
+use crate::{Lit, LitKind};
+
+/// Signed integer types
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum IntTy {
+  B8,
+  B16,
+  B32,
+  B64,
+  B128,
+}
+impl IntTy {
+  pub const ALL: [Self; 5] = [Self::B8, Self::B16, Self::B32, Self::B64, Self::B128];
+
+  pub fn name(self) -> Symbol {
+    match self {
+      Self::B8 => sym::b8,
+      Self::B16 => sym::b16,
+      Self::B32 => sym::b32,
+      Self::B64 => sym::b64,
+      Self::B128 => sym::b128,
+    }
+  }
+  pub const fn bit_width(self) -> u32 {
+    match self {
+      Self::B8 => 8,
+      Self::B16 => 16,
+      Self::B32 => 32,
+      Self::B64 => 64,
+      Self::B128 => 128,
+    }
+  }
+  pub const fn max(self) -> u128 {
+    (1 << (self.bit_width() - 1)) - 1
+  }
+}
+
+/// Unsigned integer types
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum UintTy {
+  N8,
+  N16,
+  N32,
+  N64,
+  N128,
+}
+impl UintTy {
+  pub const ALL: [Self; 5] = [Self::N8, Self::N16, Self::N32, Self::N64, Self::N128];
+
+  pub fn name(self) -> Symbol {
+    match self {
+      Self::N8 => sym::n8,
+      Self::N16 => sym::n16,
+      Self::N32 => sym::n32,
+      Self::N64 => sym::n64,
+      Self::N128 => sym::n128,
+    }
+  }
+  pub const fn bit_width(self) -> u32 {
+    match self {
+      Self::N8 => 8,
+      Self::N16 => 16,
+      Self::N32 => 32,
+      Self::N64 => 64,
+      Self::N128 => 128,
+    }
+  }
+  pub const fn max(self) -> u128 {
+    u128::MAX >> (128 - self.bit_width())
+  }
+}
+
+/// Floating point types
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum FloatTy {
+  Q32,
+  Q64,
+}
+impl FloatTy {
+  pub const ALL: [Self; 2] = [Self::Q32, Self::Q64];
+
+  pub fn name(self) -> Symbol {
+    match self {
+      Self::Q32 => sym::q32,
+      Self::Q64 => sym::q64,
+    }
+  }
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum LitIntType {
+  Signed(IntTy),
+  Unsigned(UintTy),
+  Unsuffixed,
+}
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum LitFloatType {
+  Suffixed(FloatTy),
+  Unsuffixed,
+}
+
+/// Typed value of a [`Lit`]
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum LitValue {
+  Bool(bool),
+  Int(u128, LitIntType),
+  Float(f64, LitFloatType),
+  Char(char),
+  Byte(u8),
+  Str(Symbol),
+  ByteStr(Rc<[u8]>),
+  /// Literal that failed to lex, an error was already reported
+  Err,
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum LitError {
+  /// `1b33`
+  InvalidIntSuffix(Symbol),
+  /// `1.5b8`
+  InvalidFloatSuffix(Symbol),
+  /// `"abc"alma`
+  InvalidSuffix(Symbol),
+  /// Doesn't fit into `u128`
+  IntTooLarge,
+  /// Infinite as `q32`/`q64`
+  FloatTooLarge,
+}
+impl fmt::Display for LitError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::InvalidIntSuffix(suffix) => {
+        write!(f, "invalid suffix `{}` for number literal", suffix.as_str())
+      }
+      Self::InvalidFloatSuffix(suffix) => {
+        write!(f, "invalid suffix `{}` for float literal", suffix.as_str())
+      }
+      Self::InvalidSuffix(suffix) => write!(f, "invalid suffix `{}` for literal", suffix.as_str()),
+      Self::IntTooLarge => f.write_str("integer literal is too large"),
+      Self::FloatTooLarge => f.write_str("float literal is out of range"),
+    }
+  }
+}
+
+impl Lit {
+  /// Parses the symbol of the literal into its value, and checks the suffix
+  ///
+  /// Ranges of the suffixed types are not checked here (`300b8` is `Int(300, B8)`), since
+  /// `-128b8` is only valid when negated
+  pub fn value(&self) -> Result<LitValue, LitError> {
+    let value = match self.kind {
+      LitKind::Int => return self.int_value(),
+      LitKind::Float => return self.float_value(),
+
+      LitKind::Bool => LitValue::Bool(self.symbol == kw::True),
+      LitKind::Char => LitValue::Char(self.symbol.as_str().chars().next().unwrap_or_default()),
+      // Bytes are cooked into chars in `0..=255`
+      LitKind::Byte => {
+        LitValue::Byte(self.symbol.as_str().chars().next().unwrap_or_default() as u8)
+      }
+      LitKind::Str | LitKind::RawStr => LitValue::Str(self.symbol),
+      LitKind::ByteStr => {
+        LitValue::ByteStr(self.symbol.as_str().chars().map(|ch| ch as u8).collect())
+      }
+      LitKind::Err => LitValue::Err,
+    };
+
+    if let Some(suffix) = self.suffix {
+      return Err(LitError::InvalidSuffix(suffix));
+    };
+    return Ok(value);
+  }
+
+  fn int_value(&self) -> Result<LitValue, LitError> {
+    let ty = match self.suffix {
+      None => LitIntType::Unsuffixed,
+      Some(suffix) => match int_ty_from_suffix(suffix) {
+        Some(ty) => ty,
+        // `1q32` is a float
+        None if float_ty_from_suffix(suffix).is_some() => return self.float_value(),
+        None => return Err(LitError::InvalidIntSuffix(suffix)),
+      },
+    };
+
+    let text = self.symbol.as_str();
+    let (radix, digits) = match text.get(..2) {
+      Some("0e") => (2, &text[2..]),
+      Some("0s") => (8, &text[2..]),
+      Some("0o") => (16, &text[2..]),
+      _ => (10, text),
+    };
+    let digits = digits.replace('_', "");
+    return match u128::from_str_radix(&digits, radix) {
+      Ok(value) => Ok(LitValue::Int(value, ty)),
+      Err(err) if *err.kind() == IntErrorKind::PosOverflow => Err(LitError::IntTooLarge),
+      // Missing or invalid digits are already reported by the lexer
+      Err(_) => Ok(LitValue::Err),
+    };
+  }
+  fn float_value(&self) -> Result<LitValue, LitError> {
+    let ty = match self.suffix {
+      None => LitFloatType::Unsuffixed,
+      Some(suffix) => match float_ty_from_suffix(suffix) {
+        Some(ty) => LitFloatType::Suffixed(ty),
+        None => return Err(LitError::InvalidFloatSuffix(suffix)),
+      },
+    };
+
+    let text = self.symbol.as_str().replace('_', "");
+    let value: f64 = text.parse().unwrap_or(f64::NAN);
+    let is_too_large = match ty {
+      LitFloatType::Suffixed(FloatTy::Q32) => (value as f32).is_infinite(),
+      _ => value.is_infinite(),
+    };
+    if is_too_large {
+      return Err(LitError::FloatTooLarge);
+    };
+
+    return Ok(LitValue::Float(value, ty));
+  }
+}
+
+pub fn int_ty_from_suffix(suffix: Symbol) -> Option<LitIntType> {
+  if let Some(ty) = IntTy::ALL.into_iter().find(|ty| ty.name() == suffix) {
+    return Some(LitIntType::Signed(ty));
+  };
+  return UintTy::ALL
+    .into_iter()
+    .find(|ty| ty.name() == suffix)
+    .map(LitIntType::Unsigned);
+}
+pub fn float_ty_from_suffix(suffix: Symbol) -> Option<FloatTy> {
+  FloatTy::ALL.into_iter().find(|ty| ty.name() == suffix)
+}
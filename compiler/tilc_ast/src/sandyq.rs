@@ -1,7 +1,7 @@
 use std::sync::atomic::{AtomicU32, Ordering};
 
 use tilc_macros::uidx;
-use tilc_span::{Ident, Span, Symbol};
+use tilc_span::{Ident, Span, Spanned, Symbol};
 
 use crate::{Delim, DelimSpan, Lit, TokenStream};
 
@@ -41,6 +41,7 @@ pub struct Attribute {
 
 #[derive(Debug)]
 #[derive(Clone, Copy)]
+#[derive(PartialEq)]
 pub enum AttributeStyle {
   Inner,
   Outer,
@@ -121,7 +122,8 @@ pub struct Const {
   pub ident: Ident,
   pub generics: Generics,
   pub ty: Box<Ty>,
-  pub expr: Box<Expr>,
+  /// Only trait consts can omit the value: `turaqty N: b32;`
+  pub expr: Option<Box<Expr>>,
 
   pub defaultness: Defaultness,
 }
@@ -183,6 +185,8 @@ pub enum FnReturnType {
 pub struct Param {
   pub idx: NodeIdx,
 
+  pub pat: Box<Pattern>,
+  /// `Infer` for closure parameters without a type
   pub ty: Box<Ty>,
   pub span: Span,
 }
@@ -197,14 +201,33 @@ pub struct Generics {
 pub struct GenericParam {
   pub idx: NodeIdx,
   pub ident: Ident,
+  pub bounds: Vec<GenericBound>,
   pub kind: GenericParamKind,
 }
 #[derive(Debug)]
 #[derive(Clone)]
 pub enum GenericParamKind {
+  /// 'a: 'b
   Lifetime,
+  /// T: Trait + 'a
   Type,
-  Const,
+  /// turaqty N: b32
+  Const { ty: Box<Ty> },
+}
+#[derive(Debug)]
+#[derive(Clone)]
+pub enum GenericBound {
+  /// 'a
+  Outlives(Lifetime),
+  /// Trait
+  Trait(Path),
+}
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+pub struct Lifetime {
+  pub idx: NodeIdx,
+  /// The name includes the leading `'`
+  pub ident: Ident,
 }
 
 #[derive(Debug)]
@@ -213,6 +236,8 @@ pub struct Block {
   pub idx: NodeIdx,
 
   pub statements: Vec<Statement>,
+  /// `Unsafe` for `qauipti { ... }`, `Inherit` otherwise
+  pub safety: Safety,
   pub span: Span,
 }
 
@@ -278,18 +303,41 @@ pub struct Pattern {
 #[derive(Debug)]
 #[derive(Clone)]
 pub enum PatternKind {
+  /// _
+  Wild,
+
   /// $mut? $ident $pat
   Ident(Mutability, Ident, Option<Box<Pattern>>),
 
   /// ($pat, $pat, ...)
-  Tuple(Vec<Box<Pattern>>),
-
-  /// $struct {$arg, $arg, ...}
-  Struct(Path, Vec<FieldDef>),
+  Tuple(Vec<Pattern>),
+
+  /// $path
+  Path(Path),
+  /// $path($pat, $pat, ...)
+  TupleStruct(Path, Vec<Pattern>),
+  /// $path {$field, $field, ...} or $path {$field, ..} (the bool is whether `..` is there)
+  Struct(Path, Vec<PatField>, bool),
   /// $expr
   Expr(Box<Expr>),
   /// &$mut? $pat
   Ref(Mutability, Box<Pattern>),
+  /// $expr..=$expr, $expr..$expr, ..=$expr, $expr..
+  Range(Option<Box<Expr>>, Option<Box<Expr>>, RangeLimits),
+}
+
+/// `x: $pat` or the shorthand `ayspaly x` in a struct pattern
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct PatField {
+  pub idx: NodeIdx,
+
+  pub attrs: Vec<Attribute>,
+  pub ident: Ident,
+  pub pat: Box<Pattern>,
+  pub is_shorthand: bool,
+
+  pub span: Span,
 }
 
 #[derive(Debug)]
@@ -308,8 +356,12 @@ pub struct FieldDef {
 #[derive(Debug)]
 #[derive(Clone)]
 pub enum Korpe {
+  /// `korpe a { ... }`
   Braced(Vec<Item>, Span),
+  /// `korpe a;` whose file is not loaded yet
   File,
+  /// `korpe a;` with the items of `a.til` or `a/mod.til`
+  Loaded(Vec<Item>, Span),
 }
 
 #[derive(Debug)]
@@ -328,6 +380,8 @@ pub struct TyAlias {
   pub vis: Vis,
   pub ident: Ident,
   pub generics: Generics,
+  /// `tur Item: Bound;` in traits
+  pub bounds: Vec<GenericBound>,
   pub ty: Option<Box<Ty>>,
 
   pub defaultness: Defaultness,
@@ -340,6 +394,9 @@ pub struct EnumVariant {
 
   pub attrs: Vec<Attribute>,
   pub ident: Ident,
+  pub kind: VariantKind,
+  /// Explicit discriminant: `Ras = 1`
+  pub disr_expr: Option<Box<Expr>>,
 
   pub span: Span,
 }
@@ -360,6 +417,9 @@ pub struct Trait {
   pub vis: Vis,
   pub safety: Safety,
   pub ident: Ident,
+  pub generics: Generics,
+  /// Supertraits: `qasiet A: B + 'a {}`
+  pub bounds: Vec<GenericBound>,
   pub items: Vec<Item<AssociatedItemKind>>,
 
   pub span: Span,
@@ -371,6 +431,8 @@ pub struct Impl {
   pub safety: Safety,
   pub generics: Generics,
   pub kind: ImplKind,
+  /// `Trait` of `asyru Trait ushin Type`, none for inherent impls
+  pub of_trait: Option<Box<Path>>,
   pub self_ty: Box<Ty>,
   pub items: Vec<Item<AssociatedItemKind>>,
 
@@ -421,8 +483,8 @@ pub enum TyKind {
 
   /// Reference type
   ///
-  /// [`&'a T`], [`&'a auspaly T`], [`&T`], [`&auspaly T`]
-  // Ref(Option<Box<Lifetime>>, Box<MutTy>),
+  /// [`&'a T`], [`&'a ayspaly T`], [`&T`], [`&ayspaly T`]
+  Ref(Option<Lifetime>, MutTy),
 
   // TODO:
   /// Pointer type
@@ -443,6 +505,21 @@ pub enum TyKind {
   /// (b8, b16, b32)
   Tuple(Vec<Ty>),
 
+  /// Function pointer
+  ///
+  /// fx(b32) -> b32, qauipti fx()
+  FnPtr(Box<FnPtrTy>),
+
+  /// Type of the `ozi` receiver without an explicit type
+  ///
+  /// fx f(ozi), fx f(&ayspaly ozi)
+  ImplicitSelf,
+
+  /// Variadic parameter of foreign functions
+  ///
+  /// fx printf(format: &b8, ...)
+  VarArgs,
+
   /// ```til
   /// qurylum Alma {};
   /// ```
@@ -450,6 +527,22 @@ pub enum TyKind {
   Path(Box<Path>),
 }
 
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct FnPtrTy {
+  pub safety: Safety,
+  pub decl: Box<FnDecl>,
+  /// Span of `fx($ty, ...) -> $ty`
+  pub decl_span: Span,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct MutTy {
+  pub ty: Box<Ty>,
+  pub mutability: Mutability,
+}
+
 #[derive(Debug)]
 #[derive(Clone)]
 pub enum Safety {
@@ -490,6 +583,12 @@ pub struct Path {
   pub segments: Vec<PathSegment>,
   pub span: Span,
 }
+impl Path {
+  /// Checks if the path consists of the single `name` segment
+  pub fn is_ident(&self, name: Symbol) -> bool {
+    matches!(&self.segments[..], [segment] if segment.ident.name == name)
+  }
+}
 #[derive(Debug, Clone)]
 pub struct PathSegment {
   pub ident: Ident,
@@ -515,6 +614,240 @@ pub enum ExprKind {
   Lit(Lit),
   /// $expr = $expr
   Assign(Box<Expr>, Span, Box<Expr>),
+  /// $expr += $expr
+  AssignOp(Spanned<AssignOpKind>, Box<Expr>, Box<Expr>),
+  /// $expr + $expr
+  Binary(Spanned<BinOpKind>, Box<Expr>, Box<Expr>),
+  /// -$expr, !$expr, *$expr
+  Unary(UnOp, Box<Expr>),
+  /// &$expr, &ayspaly $expr
+  AddrOf(Mutability, Box<Expr>),
+  /// $expr sekildi $ty
+  Cast(Box<Expr>, Box<Ty>),
+  /// ($expr)
+  Paren(Box<Expr>),
+  /// ($expr, $expr, ...)
+  Tuple(Vec<Expr>),
+  /// a::b::c
+  Path(Path),
+  /// $expr($expr, $expr, ...)
+  Call(Box<Expr>, Vec<Expr>),
+  /// $expr.$ident($expr, $expr, ...)
+  MethodCall(Box<MethodCall>),
+  /// $expr.$ident, $expr.0
+  Field(Box<Expr>, Ident),
+  /// $expr[$expr]
+  Index(Box<Expr>, Box<Expr>, Span),
+  /// $expr?
+  Try(Box<Expr>),
+  /// 'label: { $stmt* }, qauipti { $stmt* }
+  Block(Box<Block>, Option<Label>),
+  /// eger $expr $block bolmasa $expr
+  ///
+  /// The else branch is either another `If` or a `Block`
+  If(Box<Expr>, Box<Block>, Option<Box<Expr>>),
+  /// saikestir $expr { $arm, ... }
+  Match(Box<Expr>, Vec<Arm>),
+  /// 'label: ushin $pat ishinde $expr $block
+  ForLoop(Box<Pattern>, Box<Expr>, Box<Block>, Option<Label>),
+  /// 'label: azirshe $expr $block
+  While(Box<Expr>, Box<Block>, Option<Label>),
+  /// 'label: qaitala $block
+  Loop(Box<Block>, Option<Label>),
+  /// toqta 'label $expr
+  Break(Option<Label>, Option<Box<Expr>>),
+  /// jalgastyr 'label
+  Continue(Option<Label>),
+  /// qaitar $expr
+  Ret(Option<Box<Expr>>),
+  /// kosh |$param, ...| -> $ty $expr
+  Closure(Box<Closure>),
+  /// $expr..$expr, $expr..=$expr, ..$expr, $expr..
+  Range(Option<Box<Expr>>, Option<Box<Expr>>, RangeLimits),
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum RangeLimits {
+  /// `..`
+  HalfOpen,
+  /// `..=`
+  Closed,
+}
+impl ExprKind {
+  /// Expressions that end with a block, they don't need a `;` to be a statement
+  pub fn is_block_like(&self) -> bool {
+    matches!(
+      self,
+      Self::Block(..)
+        | Self::If(..)
+        | Self::Match(..)
+        | Self::ForLoop(..)
+        | Self::While(..)
+        | Self::Loop(..)
+    )
+  }
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Closure {
+  pub capture_by: CaptureBy,
+  pub fn_decl: Box<FnDecl>,
+  pub body: Box<Expr>,
+  /// Span of `|$param, ...| -> $ty`
+  pub fn_decl_span: Span,
+}
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+pub enum CaptureBy {
+  /// `kosh |a| a`
+  Value { move_kw: Span },
+  /// `|a| a`
+  Ref,
+}
+
+/// `'a` in `'a: qaitala {}`
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+pub struct Label {
+  pub ident: Ident,
+}
+
+/// $pat eger $guard => $expr
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Arm {
+  pub idx: NodeIdx,
+
+  pub attrs: Vec<Attribute>,
+  pub pat: Box<Pattern>,
+  pub guard: Option<Box<Expr>>,
+  pub body: Box<Expr>,
+
+  pub span: Span,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct MethodCall {
+  pub seg: PathSegment,
+  pub receiver: Box<Expr>,
+  pub args: Vec<Expr>,
+  /// Span of `.$ident($expr, ...)`
+  pub span: Span,
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum BinOpKind {
+  /// `+`
+  Add,
+  /// `-`
+  Sub,
+  /// `*`
+  Mul,
+  /// `/`
+  Div,
+  /// `%`
+  Rem,
+  /// `&&`
+  And,
+  /// `||`
+  Or,
+  /// `^`
+  BitXor,
+  /// `&`
+  BitAnd,
+  /// `|`
+  BitOr,
+  /// `<<`
+  Shl,
+  /// `>>`
+  Shr,
+  /// `==`
+  Eq,
+  /// `<`
+  Lt,
+  /// `<=`
+  Le,
+  /// `!=`
+  Ne,
+  /// `>=`
+  Ge,
+  /// `>`
+  Gt,
+}
+impl BinOpKind {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::Add => "+",
+      Self::Sub => "-",
+      Self::Mul => "*",
+      Self::Div => "/",
+      Self::Rem => "%",
+      Self::And => "&&",
+      Self::Or => "||",
+      Self::BitXor => "^",
+      Self::BitAnd => "&",
+      Self::BitOr => "|",
+      Self::Shl => "<<",
+      Self::Shr => ">>",
+      Self::Eq => "==",
+      Self::Lt => "<",
+      Self::Le => "<=",
+      Self::Ne => "!=",
+      Self::Ge => ">=",
+      Self::Gt => ">",
+    }
+  }
+  pub fn is_comparison(self) -> bool {
+    matches!(
+      self,
+      Self::Eq | Self::Lt | Self::Le | Self::Ne | Self::Ge | Self::Gt
+    )
+  }
+}
+
+/// Operator of the compound assignment, `&&=` and `||=` don't exist
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum AssignOpKind {
+  /// `+=`
+  AddAssign,
+  /// `-=`
+  SubAssign,
+  /// `*=`
+  MulAssign,
+  /// `/=`
+  DivAssign,
+  /// `%=`
+  RemAssign,
+  /// `^=`
+  BitXorAssign,
+  /// `&=`
+  BitAndAssign,
+  /// `|=`
+  BitOrAssign,
+  /// `<<=`
+  ShlAssign,
+  /// `>>=`
+  ShrAssign,
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum UnOp {
+  /// `*`
+  Deref,
+  /// `!`
+  Not,
+  /// `-`
+  Neg,
 }
 
 #[derive(Debug)]
@@ -1,4 +1,8 @@
-use tilc_span::{Ident, Span, Symbol};
+use std::fmt;
+
+use tilc_span::{Ident, KeywordScript, Span, Symbol, kw};
+
+use crate::AttributeStyle;
 
 #[derive(Debug)]
 #[derive(Clone, Copy)]
@@ -20,6 +24,7 @@ impl Token {
     let kind: TokenKind = match self.kind {
       Eq => match next_token.kind {
         Eq => EqEq,
+        Gt => FatArrow,
         _ => return None,
       },
       Lt => match next_token.kind {
@@ -49,6 +54,7 @@ impl Token {
       },
       DotDot => match next_token.kind {
         Dot => DotDotDot,
+        Eq => DotDotEq,
         _ => return None,
       },
       Colon => match next_token.kind {
@@ -56,7 +62,6 @@ impl Token {
         _ => return None,
       },
 
-      // TODO: Glue single quote and identifier tokens for producting lifetimes
       _ => return None,
     };
 
@@ -77,12 +82,41 @@ impl Token {
       _ => None,
     }
   }
+  pub fn lifetime(&self) -> Option<Ident> {
+    match self.kind {
+      TokenKind::Lifetime(name, _) => Some(Ident {
+        name,
+        span: self.span,
+      }),
+      _ => None,
+    }
+  }
+  /// Literal of the token, `ras`/`jalgan` are matched by their spelling in the `script` in case
+  /// it was changed after lexing
+  pub fn lit(&self, script: KeywordScript) -> Option<Lit> {
+    match self.kind {
+      TokenKind::Literal(lit) => Some(lit),
+      TokenKind::Ident(name, false) => match name.kw_from(script) {
+        Some(kw @ (kw::True | kw::False)) => Some(Lit {
+          kind: LitKind::Bool,
+          symbol: kw,
+          suffix: None,
+        }),
+        _ => None,
+      },
+      _ => None,
+    }
+  }
   pub fn is_kw(&self, kw: Symbol) -> bool {
     match self.kind {
       TokenKind::Ident(name, false) if name == kw => true,
       _ => false,
     }
   }
+  /// Same as [`Token::is_kw`], but `kw` is matched by its spelling in the `script`
+  pub fn is_kw_in(&self, kw: Symbol, script: KeywordScript) -> bool {
+    self.is_kw(kw.kw_in(script))
+  }
 }
 
 #[derive(Debug)]
@@ -92,6 +126,8 @@ pub enum TokenKind {
   Ident(Symbol, bool),
   Literal(Lit),
   Lifetime(Symbol, bool),
+  /// Contents of a doc comment without the `///`, `//!`, `/**` or `/*!` and `*/`
+  DocComment(CommentKind, AttributeStyle, Symbol),
 
   /// '='
   Eq,
@@ -125,6 +161,8 @@ pub enum TokenKind {
   DotDot,
   /// '...'
   DotDotDot,
+  /// '..='
+  DotDotEq,
   /// ','
   Comma,
   /// ':'
@@ -135,6 +173,8 @@ pub enum TokenKind {
   Path,
   /// "->"
   RArrow,
+  /// "=>"
+  FatArrow,
   /// "<-"
   LArrow,
   /// '#'
@@ -158,6 +198,68 @@ pub enum TokenKind {
   Unknown,
 }
 
+/// Source form of the token, used by the diagnostics
+impl fmt::Display for TokenKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    use TokenKind::*;
+
+    let str = match self {
+      Ident(name, false) => return f.write_str(name.as_str()),
+      Ident(name, true) => return write!(f, "s#{}", name.as_str()),
+      Literal(lit) => return lit.fmt(f),
+      Lifetime(name, _) => return f.write_str(name.as_str()),
+      DocComment(..) => "doc comment",
+
+      Eq => "=",
+      EqEq => "==",
+      Lt => "<",
+      Le => "<=",
+      Gt => ">",
+      Ge => ">=",
+      AndAnd => "&&",
+      OrOr => "||",
+      Bang => "!",
+      NotEq => "!=",
+      BinOp(op) => op.as_str(),
+      BinOpEq(op) => return write!(f, "{}=", op.as_str()),
+
+      At => "@",
+      Dot => ".",
+      DotDot => "..",
+      DotDotDot => "...",
+      DotDotEq => "..=",
+      Comma => ",",
+      Colon => ":",
+      Semicolon => ";",
+      Path => "::",
+      RArrow => "->",
+      FatArrow => "=>",
+      LArrow => "<-",
+      Hashtag => "#",
+      Tilde => "~",
+      Question => "?",
+      Dollar => "$",
+      Caret => "^",
+      Percent => "%",
+
+      OpenDelim(delim) => delim.open_str(),
+      CloseDelim(delim) => delim.close_str(),
+
+      Eof => "<eof>",
+      Unknown => "<unknown>",
+    };
+    f.write_str(str)
+  }
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum CommentKind {
+  Line,
+  Block,
+}
+
 #[derive(Debug)]
 #[derive(Clone, Copy)]
 #[derive(PartialEq)]
@@ -167,6 +269,25 @@ pub struct Lit {
   pub suffix: Option<Symbol>,
 }
 
+impl fmt::Display for Lit {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let symbol = self.symbol.as_str();
+    match self.kind {
+      LitKind::Char => write!(f, "'{symbol}'")?,
+      LitKind::Byte => write!(f, "b'{symbol}'")?,
+      LitKind::Str => write!(f, "\"{symbol}\"")?,
+      LitKind::ByteStr => write!(f, "b\"{symbol}\"")?,
+      LitKind::RawStr => write!(f, "s\"{symbol}\"")?,
+      LitKind::Bool | LitKind::Int | LitKind::Float | LitKind::Err => f.write_str(symbol)?,
+    };
+    if let Some(suffix) = self.suffix {
+      f.write_str(suffix.as_str())?;
+    };
+
+    Ok(())
+  }
+}
+
 #[derive(Debug)]
 #[derive(Clone, Copy)]
 #[derive(PartialEq)]
@@ -175,8 +296,12 @@ pub enum LitKind {
   Int,
   Float,
   Char,
+  Byte,
   Str,
+  ByteStr,
   RawStr,
+  /// Literal that failed to lex, an error was already reported
+  Err,
 }
 
 #[derive(Debug)]
@@ -201,6 +326,21 @@ pub enum BinOp {
   Or,
 }
 
+impl BinOp {
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::Plus => "+",
+      Self::Minus => "-",
+      Self::Star => "*",
+      Self::Slash => "/",
+      Self::Percent => "%",
+      Self::Caret => "^",
+      Self::And => "&",
+      Self::Or => "|",
+    }
+  }
+}
+
 #[derive(Debug)]
 #[derive(Clone, Copy)]
 #[derive(PartialEq)]
@@ -214,3 +354,21 @@ pub enum Delim {
 
   Empty,
 }
+impl Delim {
+  pub const fn open_str(self) -> &'static str {
+    match self {
+      Self::Paren => "(",
+      Self::Brace => "{",
+      Self::Bracket => "[",
+      Self::Empty => "",
+    }
+  }
+  pub const fn close_str(self) -> &'static str {
+    match self {
+      Self::Paren => ")",
+      Self::Brace => "}",
+      Self::Bracket => "]",
+      Self::Empty => "",
+    }
+  }
+}
@@ -0,0 +1,355 @@
+//! Read-only traversal of the AST
+//!
+//! Every `visit_*` method walks into the children by default, so a visitor only overrides
+//! the nodes it is interested in (and calls the matching `walk_*` to keep going deeper)
+
+use crate::{
+  Arm, AssociatedItemKind, AttrArgs, Attribute, Block, Expr, ExprKind, FnDecl, FnReturnType, Item,
+  ItemKind, Korpe, LocalKind, Pattern, PatternKind, Sandyq, Statement, StatementKind, Ty, TyKind,
+  VariantKind,
+};
+
+pub trait Visitor: Sized {
+  fn visit_sandyq(&mut self, sandyq: &Sandyq) {
+    walk_sandyq(self, sandyq);
+  }
+  fn visit_attribute(&mut self, attr: &Attribute) {
+    walk_attribute(self, attr);
+  }
+  fn visit_item(&mut self, item: &Item) {
+    walk_item(self, item);
+  }
+  fn visit_associated_item(&mut self, item: &Item<AssociatedItemKind>) {
+    walk_associated_item(self, item);
+  }
+  fn visit_block(&mut self, block: &Block) {
+    walk_block(self, block);
+  }
+  fn visit_statement(&mut self, statement: &Statement) {
+    walk_statement(self, statement);
+  }
+  fn visit_expr(&mut self, expr: &Expr) {
+    walk_expr(self, expr);
+  }
+  fn visit_arm(&mut self, arm: &Arm) {
+    walk_arm(self, arm);
+  }
+  fn visit_pattern(&mut self, pattern: &Pattern) {
+    walk_pattern(self, pattern);
+  }
+  fn visit_ty(&mut self, ty: &Ty) {
+    walk_ty(self, ty);
+  }
+}
+
+pub fn walk_sandyq<V: Visitor>(visitor: &mut V, sandyq: &Sandyq) {
+  sandyq
+    .attrs
+    .iter()
+    .for_each(|attr| visitor.visit_attribute(attr));
+  sandyq
+    .items
+    .iter()
+    .for_each(|item| visitor.visit_item(item));
+}
+pub fn walk_attribute<V: Visitor>(visitor: &mut V, attr: &Attribute) {
+  if let AttrArgs::Eq { expr, .. } = &attr.args {
+    visitor.visit_expr(expr);
+  };
+}
+pub fn walk_item<V: Visitor>(visitor: &mut V, item: &Item) {
+  item
+    .attrs
+    .iter()
+    .for_each(|attr| visitor.visit_attribute(attr));
+
+  match &item.kind {
+    ItemKind::Static(static_item) => {
+      visitor.visit_ty(&static_item.ty);
+      visitor.visit_expr(&static_item.expr);
+    }
+    ItemKind::Const(const_item) => {
+      visitor.visit_ty(&const_item.ty);
+      if let Some(expr) = &const_item.expr {
+        visitor.visit_expr(expr);
+      };
+    }
+    ItemKind::Fn(function) => {
+      walk_fn_decl(visitor, &function.fn_sig.fn_decl);
+      if let Some(block) = &function.block {
+        visitor.visit_block(block);
+      };
+    }
+    ItemKind::Korpe(_, _, Korpe::Braced(items, _) | Korpe::Loaded(items, _)) => {
+      items.iter().for_each(|item| visitor.visit_item(item));
+    }
+    ItemKind::TyAlias(alias) => {
+      if let Some(ty) = &alias.ty {
+        visitor.visit_ty(ty);
+      };
+    }
+    ItemKind::Struct(_, _, variant_kind) | ItemKind::Union(_, _, variant_kind) => {
+      walk_variant_kind(visitor, variant_kind);
+    }
+    ItemKind::Enum(_, _, variants) => {
+      variants.iter().for_each(|variant| {
+        variant
+          .attrs
+          .iter()
+          .for_each(|attr| visitor.visit_attribute(attr));
+        walk_variant_kind(visitor, &variant.kind);
+        if let Some(disr_expr) = &variant.disr_expr {
+          visitor.visit_expr(disr_expr);
+        };
+      });
+    }
+    ItemKind::Trait(trait_item) => {
+      trait_item
+        .items
+        .iter()
+        .for_each(|item| visitor.visit_associated_item(item));
+    }
+    ItemKind::Impl(impl_item) => {
+      visitor.visit_ty(&impl_item.self_ty);
+      impl_item
+        .items
+        .iter()
+        .for_each(|item| visitor.visit_associated_item(item));
+    }
+
+    ItemKind::Use(_)
+    | ItemKind::Korpe(_, _, Korpe::File)
+    | ItemKind::ForeignKorpe(_)
+    | ItemKind::MacroCall(_)
+    | ItemKind::MacroDef(..) => {}
+  };
+}
+pub fn walk_associated_item<V: Visitor>(visitor: &mut V, item: &Item<AssociatedItemKind>) {
+  item
+    .attrs
+    .iter()
+    .for_each(|attr| visitor.visit_attribute(attr));
+
+  match &item.kind {
+    AssociatedItemKind::Const(const_item) => {
+      visitor.visit_ty(&const_item.ty);
+      if let Some(expr) = &const_item.expr {
+        visitor.visit_expr(expr);
+      };
+    }
+    AssociatedItemKind::Fn(function) => {
+      walk_fn_decl(visitor, &function.fn_sig.fn_decl);
+      if let Some(block) = &function.block {
+        visitor.visit_block(block);
+      };
+    }
+    AssociatedItemKind::TyAlias(alias) => {
+      if let Some(ty) = &alias.ty {
+        visitor.visit_ty(ty);
+      };
+    }
+  };
+}
+pub fn walk_block<V: Visitor>(visitor: &mut V, block: &Block) {
+  block
+    .statements
+    .iter()
+    .for_each(|statement| visitor.visit_statement(statement));
+}
+pub fn walk_statement<V: Visitor>(visitor: &mut V, statement: &Statement) {
+  match &statement.kind {
+    StatementKind::Let(local) => {
+      local
+        .attrs
+        .iter()
+        .for_each(|attr| visitor.visit_attribute(attr));
+      visitor.visit_pattern(&local.pat);
+      if let Some(ty) = &local.ty {
+        visitor.visit_ty(ty);
+      };
+      match &local.kind {
+        LocalKind::Decl => {}
+        LocalKind::Init(expr) => visitor.visit_expr(expr),
+        LocalKind::InitElse(expr, block) => {
+          visitor.visit_expr(expr);
+          visitor.visit_block(block);
+        }
+      };
+    }
+    StatementKind::Item(item) => visitor.visit_item(item),
+    StatementKind::Expr(expr) | StatementKind::Semi(expr) => visitor.visit_expr(expr),
+  };
+}
+pub fn walk_expr<V: Visitor>(visitor: &mut V, expr: &Expr) {
+  expr
+    .attrs
+    .iter()
+    .for_each(|attr| visitor.visit_attribute(attr));
+
+  match &expr.kind {
+    ExprKind::Let(pattern, init, _) => {
+      visitor.visit_pattern(pattern);
+      visitor.visit_expr(init);
+    }
+    ExprKind::Assign(lhs, _, rhs)
+    | ExprKind::AssignOp(_, lhs, rhs)
+    | ExprKind::Binary(_, lhs, rhs) => {
+      visitor.visit_expr(lhs);
+      visitor.visit_expr(rhs);
+    }
+    ExprKind::Unary(_, expr) | ExprKind::AddrOf(_, expr) | ExprKind::Paren(expr) => {
+      visitor.visit_expr(expr);
+    }
+    ExprKind::Cast(expr, ty) => {
+      visitor.visit_expr(expr);
+      visitor.visit_ty(ty);
+    }
+    ExprKind::Tuple(exprs) => exprs.iter().for_each(|expr| visitor.visit_expr(expr)),
+    ExprKind::Call(callee, args) => {
+      visitor.visit_expr(callee);
+      args.iter().for_each(|arg| visitor.visit_expr(arg));
+    }
+    ExprKind::MethodCall(method_call) => {
+      visitor.visit_expr(&method_call.receiver);
+      method_call
+        .args
+        .iter()
+        .for_each(|arg| visitor.visit_expr(arg));
+    }
+    ExprKind::Field(expr, _) | ExprKind::Try(expr) => visitor.visit_expr(expr),
+    ExprKind::Index(expr, index, _) => {
+      visitor.visit_expr(expr);
+      visitor.visit_expr(index);
+    }
+    ExprKind::Block(block, _) | ExprKind::Loop(block, _) => visitor.visit_block(block),
+    ExprKind::If(cond, block, else_expr) => {
+      visitor.visit_expr(cond);
+      visitor.visit_block(block);
+      if let Some(else_expr) = else_expr {
+        visitor.visit_expr(else_expr);
+      };
+    }
+    ExprKind::Match(expr, arms) => {
+      visitor.visit_expr(expr);
+      arms.iter().for_each(|arm| visitor.visit_arm(arm));
+    }
+    ExprKind::ForLoop(pattern, iter, block, _) => {
+      visitor.visit_pattern(pattern);
+      visitor.visit_expr(iter);
+      visitor.visit_block(block);
+    }
+    ExprKind::While(cond, block, _) => {
+      visitor.visit_expr(cond);
+      visitor.visit_block(block);
+    }
+    ExprKind::Break(_, expr) | ExprKind::Ret(expr) => {
+      if let Some(expr) = expr {
+        visitor.visit_expr(expr);
+      };
+    }
+    ExprKind::Closure(closure) => {
+      walk_fn_decl(visitor, &closure.fn_decl);
+      visitor.visit_expr(&closure.body);
+    }
+    ExprKind::Range(start, end, _) => {
+      if let Some(start) = start {
+        visitor.visit_expr(start);
+      };
+      if let Some(end) = end {
+        visitor.visit_expr(end);
+      };
+    }
+    ExprKind::Continue(_) => {}
+
+    ExprKind::Lit(_) | ExprKind::Path(_) => {}
+  };
+}
+pub fn walk_arm<V: Visitor>(visitor: &mut V, arm: &Arm) {
+  arm
+    .attrs
+    .iter()
+    .for_each(|attr| visitor.visit_attribute(attr));
+  visitor.visit_pattern(&arm.pat);
+  if let Some(guard) = &arm.guard {
+    visitor.visit_expr(guard);
+  };
+  visitor.visit_expr(&arm.body);
+}
+pub fn walk_pattern<V: Visitor>(visitor: &mut V, pattern: &Pattern) {
+  match &pattern.kind {
+    PatternKind::Ident(_, _, sub_pattern) => {
+      if let Some(sub_pattern) = sub_pattern {
+        visitor.visit_pattern(sub_pattern);
+      };
+    }
+    PatternKind::Tuple(patterns) => {
+      patterns
+        .iter()
+        .for_each(|pattern| visitor.visit_pattern(pattern));
+    }
+    PatternKind::Path(_) => {}
+    PatternKind::TupleStruct(_, patterns) => {
+      patterns
+        .iter()
+        .for_each(|pattern| visitor.visit_pattern(pattern));
+    }
+    PatternKind::Struct(_, fields, _) => {
+      fields.iter().for_each(|field| {
+        field
+          .attrs
+          .iter()
+          .for_each(|attr| visitor.visit_attribute(attr));
+        visitor.visit_pattern(&field.pat);
+      });
+    }
+    PatternKind::Expr(expr) => visitor.visit_expr(expr),
+    PatternKind::Ref(_, pattern) => visitor.visit_pattern(pattern),
+    PatternKind::Range(start, end, _) => {
+      if let Some(start) = start {
+        visitor.visit_expr(start);
+      };
+      if let Some(end) = end {
+        visitor.visit_expr(end);
+      };
+    }
+    PatternKind::Wild => {}
+  };
+}
+pub fn walk_ty<V: Visitor>(visitor: &mut V, ty: &Ty) {
+  match &ty.kind {
+    TyKind::Ref(_, mut_ty) => visitor.visit_ty(&mut_ty.ty),
+    TyKind::Slice(ty) => visitor.visit_ty(ty),
+    TyKind::Tuple(tys) => tys.iter().for_each(|ty| visitor.visit_ty(ty)),
+    TyKind::FnPtr(fn_ptr) => walk_fn_decl(visitor, &fn_ptr.decl),
+
+    TyKind::Never
+    | TyKind::Infer
+    | TyKind::Ptr()
+    | TyKind::ImplicitSelf
+    | TyKind::VarArgs
+    | TyKind::Path(_) => {}
+  };
+}
+pub fn walk_fn_decl<V: Visitor>(visitor: &mut V, fn_decl: &FnDecl) {
+  fn_decl.params.iter().for_each(|param| {
+    visitor.visit_pattern(&param.pat);
+    visitor.visit_ty(&param.ty);
+  });
+  if let FnReturnType::Other(ty) = &fn_decl.return_ty {
+    visitor.visit_ty(ty);
+  };
+}
+pub fn walk_variant_kind<V: Visitor>(visitor: &mut V, variant_kind: &VariantKind) {
+  match variant_kind {
+    VariantKind::Struct(fields) | VariantKind::Tuple(fields) => {
+      fields.iter().for_each(|field| {
+        field
+          .attrs
+          .iter()
+          .for_each(|attr| visitor.visit_attribute(attr));
+        visitor.visit_ty(&field.ty);
+      });
+    }
+    VariantKind::Unit => {}
+  };
+}
@@ -1 +1,117 @@
+use std::{fmt, rc::Rc};
 
+use tilc_span::{Pos, Span};
+
+use crate::{Diag, DiagInner, EmissionGuarantee, Level};
+
+impl<'a, E> Diag<'a, E>
+where
+  E: EmissionGuarantee,
+{
+  pub fn with_span(mut self, span: Span) -> Self {
+    self.inner_mut().span = span;
+    self
+  }
+  /// Attaches a secondary span with its own message
+  pub fn with_label(mut self, span: Span, message: impl Into<Rc<str>>) -> Self {
+    self.inner_mut().labels.push((span, message.into()));
+    self
+  }
+  pub fn with_note(mut self, message: impl Into<Rc<str>>) -> Self {
+    self.inner_mut().children.push(SubDiag {
+      level: Level::Note,
+      message: message.into(),
+      span: None,
+    });
+    self
+  }
+  pub fn with_help(mut self, message: impl Into<Rc<str>>) -> Self {
+    self.inner_mut().children.push(SubDiag {
+      level: Level::Help,
+      message: message.into(),
+      span: None,
+    });
+    self
+  }
+  /// Proposes replacing the text at `span` by `replacement`
+  pub fn with_suggestion(
+    mut self,
+    span: Span,
+    message: impl Into<Rc<str>>,
+    replacement: impl Into<Rc<str>>,
+  ) -> Self {
+    self.inner_mut().suggestions.push(Suggestion {
+      message: message.into(),
+      span,
+      replacement: replacement.into(),
+    });
+    self
+  }
+
+  /// Drops the diagnostic without reporting it
+  pub fn cancel(mut self) {
+    self.diag.take();
+  }
+
+  fn inner_mut(&mut self) -> &mut DiagInner {
+    self.diag.as_mut().unwrap_or_else(|| unreachable!())
+  }
+}
+
+impl DiagInner {
+  pub(crate) fn new(level: Level, message: impl Into<Rc<str>>) -> Self {
+    Self {
+      level,
+      message: message.into(),
+
+      span: Span::EMPTY,
+      labels: Vec::new(),
+      children: Vec::new(),
+      suggestions: Vec::new(),
+    }
+  }
+}
+impl fmt::Display for DiagInner {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}: {}", self.level, self.message)?;
+    if self.span != Span::EMPTY {
+      write!(f, "\n  --> {}", DisplaySpan(self.span))?;
+    };
+    for (span, message) in self.labels.iter() {
+      write!(f, "\n  --> {}: {}", DisplaySpan(*span), message)?;
+    }
+    for child in self.children.iter() {
+      write!(f, "\n  = {}: {}", child.level, child.message)?;
+    }
+    for suggestion in self.suggestions.iter() {
+      write!(
+        f,
+        "\n  = help: {}: `{}`",
+        suggestion.message, suggestion.replacement
+      )?;
+    }
+
+    Ok(())
+  }
+}
+
+#[derive(Debug)]
+pub struct SubDiag {
+  pub level: Level,
+  pub message: Rc<str>,
+  pub span: Option<Span>,
+}
+
+#[derive(Debug)]
+pub struct Suggestion {
+  pub message: Rc<str>,
+  pub span: Span,
+  pub replacement: Rc<str>,
+}
+
+struct DisplaySpan(Span);
+impl fmt::Display for DisplaySpan {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}..{}", self.0.lo().to_u32(), self.0.hi().to_u32())
+  }
+}
@@ -2,7 +2,7 @@ mod diagnostic;
 
 pub use diagnostic::*;
 
-use std::{cell::RefCell, marker::PhantomData, rc::Rc};
+use std::{cell::RefCell, fmt, marker::PhantomData, rc::Rc};
 
 use tilc_span::Span;
 
@@ -20,6 +20,14 @@ impl<'a, E> Diag<'a, E>
 where
   E: EmissionGuarantee,
 {
+  pub fn new(dcx: DiagCtxtHandle<'a>, level: Level, message: impl Into<Rc<str>>) -> Self {
+    Self {
+      dcx,
+      diag: Some(Box::new(DiagInner::new(level, message))),
+      marker: PhantomData,
+    }
+  }
+
   pub fn emit(self) -> E::EmissionResult {
     E::emit_guarantee(self)
   }
@@ -30,24 +38,112 @@ pub struct DiagInner {
   pub message: Rc<str>,
 
   pub span: Span,
+  pub labels: Vec<(Span, Rc<str>)>,
+  pub children: Vec<SubDiag>,
+  pub suggestions: Vec<Suggestion>,
 }
 #[derive(Debug)]
 #[derive(Clone, Copy)]
 pub struct DiagCtxtHandle<'a> {
   dcx: &'a DiagCtxt,
 }
+impl<'a> DiagCtxtHandle<'a> {
+  pub fn struct_err(self, message: impl Into<Rc<str>>) -> Diag<'a> {
+    Diag::new(self, Level::Error, message)
+  }
+  pub fn struct_span_err(self, span: Span, message: impl Into<Rc<str>>) -> Diag<'a> {
+    self.struct_err(message).with_span(span)
+  }
+  pub fn struct_span_warn(self, span: Span, message: impl Into<Rc<str>>) -> Diag<'a, ()> {
+    Diag::new(self, Level::Warning, message).with_span(span)
+  }
+  pub fn emit_err(self, span: Span, message: impl Into<Rc<str>>) -> ErrorGuaranteed {
+    self.struct_span_err(span, message).emit()
+  }
+
+  pub fn err_count(self) -> usize {
+    self.dcx.inner.borrow().err_count
+  }
+  pub fn has_errors(self) -> Option<ErrorGuaranteed> {
+    if self.err_count() > 0 {
+      // SAFETY: at least one error was emitted through this context
+      Some(unsafe { ErrorGuaranteed::new_unchecked() })
+    } else {
+      None
+    }
+  }
+  pub fn with_emitted<F, R>(self, f: F) -> R
+  where
+    F: FnOnce(&[DiagInner]) -> R, {
+    f(&self.dcx.inner.borrow().emitted)
+  }
+
+  /// Takes the diagnostics emitted so far, to forward the ones of a silent context
+  pub fn take_emitted(self) -> Vec<DiagInner> {
+    std::mem::take(&mut self.dcx.inner.borrow_mut().emitted)
+  }
+
+  pub fn emit_diagnostic(self, diag: DiagInner) {
+    let mut inner = self.dcx.inner.borrow_mut();
+    if matches!(diag.level, Level::Error | Level::Fatal) {
+      inner.err_count += 1;
+    };
+
+    if !self.dcx.silent {
+      eprintln!("{}", diag);
+    };
+    inner.emitted.push(diag);
+  }
+}
 #[derive(Debug)]
 pub struct DiagCtxt {
   inner: RefCell<DiagCtxtInner>,
+  /// Diagnostics are only recorded, not printed
+  silent: bool,
+}
+impl DiagCtxt {
+  pub fn new() -> Self {
+    Self {
+      inner: RefCell::new(DiagCtxtInner {
+        emitted: Vec::new(),
+        err_count: 0,
+      }),
+      silent: false,
+    }
+  }
+  /// Context for throwaway work whose diagnostics shouldn't reach the user
+  pub fn silent() -> Self {
+    Self {
+      silent: true,
+      ..Self::new()
+    }
+  }
+
+  pub fn handle(&self) -> DiagCtxtHandle<'_> {
+    DiagCtxtHandle { dcx: self }
+  }
+}
+impl Default for DiagCtxt {
+  fn default() -> Self {
+    Self::new()
+  }
 }
 #[derive(Debug)]
-struct DiagCtxtInner {}
+struct DiagCtxtInner {
+  emitted: Vec<DiagInner>,
+  err_count: usize,
+}
 
 #[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
 pub enum Level {
   /// Just some additional info
   Note,
 
+  /// Hint on how to fix the problem
+  Help,
+
   /// Skill issues
   Warning,
 
@@ -57,6 +153,17 @@ pub enum Level {
   /// Failure during preparation to compilation stage
   Fatal,
 }
+impl fmt::Display for Level {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Note => "note",
+      Self::Help => "help",
+      Self::Warning => "warning",
+      Self::Error => "error",
+      Self::Fatal => "fatal error",
+    })
+  }
+}
 
 pub struct FatalError;
 impl FatalError {
@@ -75,9 +182,20 @@ impl ErrorGuaranteed {
 }
 impl EmissionGuarantee for ErrorGuaranteed {
   type EmissionResult = Self;
-  fn emit_guarantee(diag: Diag<'_, Self>) -> Self::EmissionResult {
-    todo!();
-    // diag.emit_error_guaranteed()
+  fn emit_guarantee(mut diag: Diag<'_, Self>) -> Self::EmissionResult {
+    let inner = diag.diag.take().unwrap_or_else(|| unreachable!());
+    debug_assert!(matches!(inner.level, Level::Error | Level::Fatal));
+    diag.dcx.emit_diagnostic(*inner);
+
+    // SAFETY: the error was just emitted
+    unsafe { ErrorGuaranteed::new_unchecked() }
+  }
+}
+impl EmissionGuarantee for () {
+  type EmissionResult = ();
+  fn emit_guarantee(mut diag: Diag<'_, Self>) -> Self::EmissionResult {
+    let inner = diag.diag.take().unwrap_or_else(|| unreachable!());
+    diag.dcx.emit_diagnostic(*inner);
   }
 }
 
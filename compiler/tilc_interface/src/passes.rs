@@ -1,31 +1,50 @@
 use std::{
-  path::PathBuf,
+  path::{Path, PathBuf},
   sync::{Arc, OnceLock},
 };
 
 use tilc_ast::Sandyq;
 use tilc_data_structures::Holder;
-use tilc_error::FatalError;
 use tilc_middle::{
   Arena, DEFAULT_QUERY_PROVIDERS, QueryCaches, QueryFns, QuerySystem, TyCtxt, queries::Providers,
 };
-use tilc_parse::new_parser_from_file;
+use tilc_parse::{
+  check_literals, check_non_ascii_idents, load_korpe_files, migrate_keyword_script,
+  new_parser_from_file,
+};
 use tilc_session::{Input, ParseSession, Session};
-use tilc_span::with_session_globals;
+use tilc_span::{KeywordScript, with_session_globals};
 
 use crate::Result;
 
 pub fn runner(args: &[String]) -> Result<()> {
-  let input_file = Input::File(match args.get(0) {
-    Some(path) => PathBuf::from(path),
-    None => FatalError.raise(),
-  });
   let source_map = with_session_globals(|session_globals| session_globals.source_map());
   let psess = ParseSession::new(source_map);
+
+  if args.first().is_some_and(|arg| arg == "--migrate-keywords") {
+    return migrate_keywords(&psess, &args[1..]);
+  };
+
+  let input_file = Input::File(match args.first() {
+    Some(path) => PathBuf::from(path),
+    None => {
+      return Err(
+        psess
+          .dcx()
+          .struct_err("no input file")
+          .with_help(USAGE)
+          .emit(),
+      );
+    }
+  });
   let session = Session { input_file, psess };
 
   let sandyq = parse(&session)?;
-  dbg!(&sandyq);
+  check_literals(&session.psess, &sandyq);
+  check_non_ascii_idents(&session.psess);
+  if let Some(guar) = session.psess.dcx().has_errors() {
+    return Err(guar);
+  };
 
   create_and_enter_global_ctxt(&session, sandyq, |tcx| {
     let _ = tcx.resolver_for_lowering_raw(());
@@ -34,16 +53,55 @@ pub fn runner(args: &[String]) -> Result<()> {
   return Ok(());
 }
 
+const USAGE: &str = "usage: tilc <file> or tilc --migrate-keywords <latyn|kirill> <file>";
+
+/// `tilc --migrate-keywords <latyn|kirill> <file>` rewrites the keywords of a file in place
+fn migrate_keywords(psess: &ParseSession, args: &[String]) -> Result<()> {
+  let (to, path) = match (args.first().map(String::as_str), args.get(1)) {
+    (Some("latyn"), Some(path)) => (KeywordScript::Latin, path),
+    (Some("kirill"), Some(path)) => (KeywordScript::Cyrillic, path),
+
+    _ => {
+      return Err(
+        psess
+          .dcx()
+          .struct_err("malformed `--migrate-keywords` arguments")
+          .with_help(USAGE)
+          .emit(),
+      );
+    }
+  };
+
+  let source_file = psess
+    .source_map()
+    .load_file(Path::new(path))
+    .map_err(|err| {
+      psess
+        .dcx()
+        .struct_err(format!("couldn't read \"{path}\": {err}"))
+        .emit()
+    })?;
+  let migrated = migrate_keyword_script(psess, source_file.src(), source_file.start(), to)?;
+  std::fs::write(path, migrated).map_err(|err| {
+    psess
+      .dcx()
+      .struct_err(format!("couldn't write \"{path}\": {err}"))
+      .emit()
+  })?;
+
+  return Ok(());
+}
+
 fn parse(session: &Session) -> Result<Sandyq> {
   let mut parser = match &session.input_file {
     Input::File(path) => new_parser_from_file(&session.psess, path),
   }?;
 
-  let sandyq = parser.parse_sandyq().map_err(|diag| {
-    dbg!(&diag);
-    diag.emit()
-  });
-  sandyq
+  let mut sandyq = parser.parse_sandyq().map_err(|diag| diag.emit())?;
+  match &session.input_file {
+    Input::File(path) => load_korpe_files(&session.psess, &mut sandyq, path),
+  };
+  Ok(sandyq)
 }
 
 fn create_and_enter_global_ctxt<F, R>(session: &Session, sandyq: Sandyq, f: F) -> R
@@ -0,0 +1,158 @@
+use std::ops::Range;
+
+use crate::{Lexer, Token, TokenKind};
+
+/// How far (in bytes) past the end of a token the lexer may look to decide where the token ends
+///
+/// At most 2 chars are inspected (e.g. `1.` followed by `.` or an identifier is not a float)
+pub const MAX_LOOKAHEAD: usize = 2 * 4;
+
+/// Replacement of `range` (byte offsets in the old source) with `new_text`
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct TextEdit {
+  pub range: Range<usize>,
+  pub new_text: String,
+}
+impl TextEdit {
+  pub fn apply(&self, src: &str) -> String {
+    let mut new_src = String::with_capacity(src.len() - self.range.len() + self.new_text.len());
+    new_src.push_str(&src[..self.range.start]);
+    new_src.push_str(&self.new_text);
+    new_src.push_str(&src[self.range.end..]);
+
+    return new_src;
+  }
+
+  /// End of the inserted text in the new source
+  pub fn new_end(&self) -> usize {
+    self.range.start + self.new_text.len()
+  }
+  /// Position in the new source of `old_pos`, which must not be before the end of the edit
+  pub fn shift(&self, old_pos: usize) -> usize {
+    debug_assert!(old_pos >= self.range.end);
+    old_pos - self.range.end + self.new_end()
+  }
+  /// Position in the old source of `new_pos`, which must not be before the end of the edit
+  pub fn unshift(&self, new_pos: usize) -> usize {
+    debug_assert!(new_pos >= self.new_end());
+    new_pos - self.new_end() + self.range.end
+  }
+
+  /// Number of leading tokens (given by their end offsets) that the edit can't change
+  ///
+  /// Lexing restarts right after them, which is always outside of strings and comments,
+  /// since the lexer never looks behind the start of a token
+  pub fn restart_idx(&self, token_ends: impl IntoIterator<Item = usize>) -> usize {
+    return token_ends
+      .into_iter()
+      .take_while(|&end| end + MAX_LOOKAHEAD <= self.range.start)
+      .count();
+  }
+}
+
+#[derive(Debug)]
+pub struct Relexed {
+  pub tokens: Vec<Token>,
+  /// Indices of the new (re-lexed) tokens in `tokens`
+  pub changed: Range<usize>,
+}
+
+/// Updates `old_tokens` (produced by [`crate::tokenize`]) after the `edit`, `new_src` is the
+/// edited source
+///
+/// Only the tokens from the last safe restart point before the edit are re-lexed, until
+/// a token ends at the same place (after the edit) where an old token ended; the rest of the old
+/// tokens are reused as is
+pub fn relex(old_tokens: &[Token], new_src: &str, edit: &TextEdit) -> Relexed {
+  let restart_idx = edit.restart_idx(old_tokens.iter().scan(0, |end, token| {
+    *end += token.len;
+    Some(*end)
+  }));
+  let restart: usize = old_tokens[..restart_idx]
+    .iter()
+    .map(|token| token.len)
+    .sum();
+
+  let mut tokens = old_tokens[..restart_idx].to_vec();
+  let mut lexer = Lexer::new(&new_src[restart..]);
+  let mut pos = restart;
+
+  // Old token that may start where the next new token starts
+  let mut old_idx = restart_idx;
+  let mut old_pos = restart;
+  loop {
+    let token = lexer.char_to_token();
+    if token.kind == TokenKind::Eof {
+      // Nothing is left to reuse
+      old_idx = old_tokens.len();
+      break;
+    };
+    tokens.push(token);
+    pos += token.len;
+
+    if pos < edit.new_end() {
+      continue;
+    };
+    let old_equiv = edit.unshift(pos);
+    while old_pos < old_equiv && old_idx < old_tokens.len() {
+      old_pos += old_tokens[old_idx].len;
+      old_idx += 1;
+    }
+    if old_pos == old_equiv {
+      break;
+    };
+  }
+
+  let changed = restart_idx..tokens.len();
+  tokens.extend_from_slice(&old_tokens[old_idx..]);
+
+  return Relexed { tokens, changed };
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::tokenize;
+
+  fn check(src: &str, range: Range<usize>, new_text: &str) -> Range<usize> {
+    let edit = TextEdit {
+      range,
+      new_text: new_text.to_string(),
+    };
+    let new_src = edit.apply(src);
+
+    let old_tokens = tokenize(src).collect::<Vec<_>>();
+    let relexed = relex(&old_tokens, &new_src, &edit);
+    assert_eq!(
+      relexed.tokens,
+      tokenize(&new_src).collect::<Vec<_>>(),
+      "{new_src}"
+    );
+
+    return relexed.changed;
+  }
+
+  #[test]
+  fn relex_edits() {
+    let src = "fx basty() {\n  ainymaly alma = 1.5;\n  ainymaly jemis = \"q\";\n}\n";
+
+    // Renaming touches only the identifier (and its neighbours within the lookahead)
+    let changed = check(src, 24..28, "almurt");
+    assert!(changed.len() <= 4, "{changed:?}");
+
+    // Opening a string re-lexes until the end of the file
+    check(src, 24..24, "\"");
+    // Opening a comment
+    check(src, 15..15, "/*");
+    // `1.5` -> `1.x5`
+    check(src, 32..32, "x");
+    // `1.5` -> `1..5`
+    check(src, 32..32, ".");
+    // Deleting everything
+    check(src, 0..src.len(), "");
+    // Appending to the end
+    check(src, src.len()..src.len(), "// end");
+  }
+}
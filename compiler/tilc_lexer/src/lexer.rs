@@ -1,9 +1,10 @@
 use std::str::Chars;
 
-use crate::{Base, LiteralKind, TokenKind, token::Token};
+use crate::{Base, DocStyle, LiteralKind, RawStrError, TokenKind, token::Token};
 
 const EOF_CHAR: char = '\0';
 const RAW_CHAR: char = 's';
+const BYTE_CHAR: char = 'b';
 
 const fn is_whitespace(ch: char) -> bool {
   match ch {
@@ -12,21 +13,39 @@ const fn is_whitespace(ch: char) -> bool {
     _ => false,
   }
 }
-const fn is_ident_start(ch: char) -> bool {
+/// Identifiers follow UAX #31, so Kazakh Cyrillic (ә, ғ, қ, ң, ө, ұ, ү, һ, і)
+/// and extended Latin letters are allowed
+fn is_ident_start(ch: char) -> bool {
   match ch {
     _ if ch.is_ascii_alphabetic() || ch == '_' => true,
+    _ if ch.is_ascii() => false,
 
-    _ => false,
+    _ => unicode_ident::is_xid_start(ch),
   }
 }
-const fn is_ident(ch: char) -> bool {
+fn is_ident(ch: char) -> bool {
   match ch {
     _ if ch.is_ascii_alphanumeric() || ch == '_' => true,
+    _ if ch.is_ascii() => false,
 
-    _ => false,
+    _ => unicode_ident::is_xid_continue(ch),
   }
 }
 
+/// Every token of `src` (including whitespace and comments) without the final `Eof`
+///
+/// The lexer is lossless: the lengths of the tokens add up to `src.len()`
+pub fn tokenize(src: &str) -> impl Iterator<Item = Token> + '_ {
+  let mut lexer = Lexer::new(src);
+  std::iter::from_fn(move || {
+    let token = lexer.char_to_token();
+    if token.kind == TokenKind::Eof {
+      return None;
+    };
+    Some(token)
+  })
+}
+
 pub struct Lexer<'a> {
   chars: Chars<'a>,
   len_left: usize,
@@ -57,9 +76,24 @@ impl<'a> Lexer<'a> {
         _ => Slash,
       },
 
-      RAW_CHAR => match (self.peek(), self.nth(2)) {
+      RAW_CHAR => match (self.peek(), self.nth(1)) {
         ('#', next) if is_ident_start(next) => self.raw_ident(),
-        ('#', '\"') => self.raw_str(),
+        ('#', '#' | '\"') | ('\"', _) => self.raw_str(),
+
+        _ => self.ident(),
+      },
+
+      BYTE_CHAR => match self.peek() {
+        '\'' => {
+          self.step();
+          let terminated = self.single_quoted_string();
+          self.literal_with_suffix(LiteralKind::Byte { terminated }, terminated)
+        }
+        '\"' => {
+          self.step();
+          let terminated = self.double_quoted_string();
+          self.literal_with_suffix(LiteralKind::ByteStr { terminated }, terminated)
+        }
 
         _ => self.ident(),
       },
@@ -76,7 +110,10 @@ impl<'a> Lexer<'a> {
 
       '\'' => self.char_literal_or_lifetime(),
 
-      '\"' => todo!(),
+      '\"' => {
+        let terminated = self.double_quoted_string();
+        self.literal_with_suffix(LiteralKind::Str { terminated }, terminated)
+      }
 
       ';' => Semicolon,
       ':' => Colon,
@@ -90,6 +127,7 @@ impl<'a> Lexer<'a> {
       ']' => CloseBracket,
       '@' => At,
       '#' => Hashtag,
+      '~' => Tilde,
       '?' => Question,
       '$' => Dollar,
       '=' => Eq,
@@ -125,6 +163,9 @@ impl<'a> Lexer<'a> {
     self.len_left = self.as_str().len();
   }
 
+  fn is_eof(&self) -> bool {
+    self.chars.as_str().is_empty()
+  }
   fn nth(&self, n: u32) -> char {
     debug_assert_ne!(n, 0);
     let mut chars = self.chars.clone();
@@ -156,20 +197,137 @@ impl<'a> Lexer<'a> {
     TokenKind::Ident
   }
   fn raw_ident(&mut self) -> TokenKind {
+    debug_assert_eq!(self.step(), '#');
     self.consume(is_ident);
     TokenKind::RawIdent
   }
   fn raw_str(&mut self) -> TokenKind {
-    debug_assert_eq!(self.step(), '#');
-    debug_assert_eq!(self.step(), '\"');
-    while self.step() != '"' {}
+    let (n_hashes, err) = match self.raw_str_unvalidated() {
+      Ok(n_hashes) if n_hashes > 255 => (
+        n_hashes,
+        Some(RawStrError::TooManyDelimiters { found: n_hashes }),
+      ),
+      Ok(n_hashes) => (n_hashes, None),
+      Err((n_hashes, err)) => (n_hashes, Some(err)),
+    };
 
-    TokenKind::Literal {
-      kind: LiteralKind::RawStr,
-      suffix_pos: self.current_token_len() as u32,
+    self.literal_with_suffix(LiteralKind::RawStr { n_hashes, err }, err.is_none())
+  }
+  fn raw_str_unvalidated(&mut self) -> Result<u32, (u32, RawStrError)> {
+    let mut n_hashes = 0;
+    while self.peek() == '#' {
+      self.step();
+      n_hashes += 1;
     }
+
+    if self.peek() != '"' {
+      let bad_char = self.peek();
+      return Err((n_hashes, RawStrError::InvalidStarter { bad_char }));
+    };
+    self.step();
+
+    let mut max_hashes = 0;
+    let mut possible_terminator_offset = None;
+    loop {
+      self.consume(|ch| ch != '"' && ch != EOF_CHAR);
+      if self.is_eof() {
+        return Err((
+          n_hashes,
+          RawStrError::NoTerminator {
+            expected: n_hashes,
+            found: max_hashes,
+            possible_terminator_offset,
+          },
+        ));
+      };
+
+      // `"` that might close the literal
+      let quote_offset = self.current_token_len() as u32;
+      self.step();
+
+      let mut found = 0;
+      while found < n_hashes && self.peek() == '#' {
+        self.step();
+        found += 1;
+      }
+      if found == n_hashes {
+        return Ok(n_hashes);
+      };
+
+      if found > max_hashes {
+        max_hashes = found;
+        possible_terminator_offset = Some(quote_offset);
+      };
+    }
+  }
+  /// Consumes the suffix of a quoted literal (if the literal is terminated)
+  fn literal_with_suffix(&mut self, kind: LiteralKind, terminated: bool) -> TokenKind {
+    let suffix_pos = self.current_token_len() as u32;
+    if terminated {
+      self.consume(is_ident);
+    };
+
+    TokenKind::Literal { kind, suffix_pos }
   }
-  fn is_digit(&mut self) -> bool {
+  /// Consumes everything until the closing `'`, returns `false` if the literal is unterminated
+  fn single_quoted_string(&mut self) -> bool {
+    // '''
+    if self.nth(1) == '\'' && self.peek() != '\\' {
+      self.step();
+      self.step();
+      return true;
+    };
+
+    loop {
+      match self.peek() {
+        '\'' => {
+          self.step();
+          return true;
+        }
+        // Probably a beginning of the comment, char literals cannot span multiple lines
+        '/' | '\n' => return false,
+        EOF_CHAR if self.is_eof() => return false,
+
+        '\\' => {
+          self.step();
+          self.step();
+        }
+        _ => {
+          self.step();
+        }
+      };
+    }
+  }
+  /// Consumes everything until the closing `"`, returns `false` if the literal is unterminated
+  fn double_quoted_string(&mut self) -> bool {
+    loop {
+      if self.is_eof() {
+        return false;
+      };
+
+      match self.step() {
+        '"' => return true,
+        '\\' if matches!(self.peek(), '\\' | '"') => {
+          self.step();
+        }
+
+        _ => {}
+      };
+    }
+  }
+  /// Whether `b` and only digits up to the end of the identifier start at the `n`th char
+  fn is_signed_suffix_at(&self, n: u32) -> bool {
+    if self.nth(n) != 'b' || !self.nth(n + 1).is_ascii_digit() {
+      return false;
+    };
+
+    let mut n = n + 1;
+    while self.nth(n).is_ascii_digit() {
+      n += 1;
+    }
+    return !is_ident(self.nth(n));
+  }
+  fn eat_decimal_digits(&mut self) -> bool {
     let mut contains_digits = false;
     loop {
       match self.peek() {
@@ -182,7 +340,29 @@ impl<'a> Lexer<'a> {
           self.step();
         }
 
-        '.' | 'E' => {}
+        _ => break,
+      };
+    }
+
+    return contains_digits;
+  }
+  /// `b` is a hexadecimal digit, so a `b8`..`b128` suffix must be separated by `_`: `0oFF_b8`
+  fn eat_hexadecimal_digits(&mut self) -> bool {
+    let mut contains_digits = false;
+    loop {
+      match self.peek() {
+        '_' if self.is_signed_suffix_at(1) => {
+          self.step();
+          break;
+        }
+        '_' => {
+          self.step();
+        }
+
+        '0'..='9' | 'a'..='f' | 'A'..='F' => {
+          contains_digits = true;
+          self.step();
+        }
 
         _ => break,
       };
@@ -190,6 +370,14 @@ impl<'a> Lexer<'a> {
 
     return contains_digits;
   }
+  /// Consumes the optional sign and the digits of the exponent, `e`/`E` must be already consumed
+  fn eat_float_exponent(&mut self) -> bool {
+    if matches!(self.peek(), '-' | '+') {
+      self.step();
+    };
+
+    return self.eat_decimal_digits();
+  }
   fn number_kind(&mut self, current_char: char) -> LiteralKind {
     let mut base = Base::Decimal;
 
@@ -198,77 +386,466 @@ impl<'a> Lexer<'a> {
         'e' => {
           base = Base::Binary;
           self.step();
-          if !self.is_digit() {
-            return LiteralKind::Int { base };
+          if !self.eat_decimal_digits() {
+            return LiteralKind::Int {
+              base,
+              empty_int: true,
+            };
           };
         }
         's' => {
           base = Base::Octal;
           self.step();
-          if !self.is_digit() {
-            return LiteralKind::Int { base };
+          if !self.eat_decimal_digits() {
+            return LiteralKind::Int {
+              base,
+              empty_int: true,
+            };
+          };
+        }
+        'o' => {
+          base = Base::Hexadecimal;
+          self.step();
+          if !self.eat_hexadecimal_digits() {
+            return LiteralKind::Int {
+              base,
+              empty_int: true,
+            };
           };
         }
 
-        _ => return LiteralKind::Int { base },
+        // Not a base prefix
+        '0'..='9' | '_' => {
+          self.eat_decimal_digits();
+        }
+        '.' | 'E' => {}
+
+        _ => {
+          return LiteralKind::Int {
+            base,
+            empty_int: false,
+          };
+        }
       };
     } else {
-      self.is_digit();
+      self.eat_decimal_digits();
     };
 
     match self.peek() {
-      '.' if self.nth(2) != '.' => {
-        todo!();
+      // `1..2` is a range and `1.alma` is a field access or a method call
+      '.' if self.nth(1) != '.' && !is_ident_start(self.nth(1)) => {
+        self.step();
+
+        let mut empty_exponent = false;
+        if self.peek().is_ascii_digit() {
+          self.eat_decimal_digits();
+          if matches!(self.peek(), 'e' | 'E') {
+            self.step();
+            empty_exponent = !self.eat_float_exponent();
+          };
+        };
+
+        LiteralKind::Float {
+          base,
+          empty_exponent,
+        }
+      }
+      'e' | 'E' => {
+        self.step();
+        let empty_exponent = !self.eat_float_exponent();
+
+        LiteralKind::Float {
+          base,
+          empty_exponent,
+        }
       }
 
       _ => LiteralKind::Int {
-        base: Base::Decimal,
+        base,
+        empty_int: false,
       },
     }
   }
   fn line_comment(&mut self) -> TokenKind {
     debug_assert_eq!(self.step(), '/');
 
-    loop {
-      if self.step() == '\n' {
-        break TokenKind::LineComment;
-      };
-    }
+    let doc_style = match self.peek() {
+      // `////` is an ordinary comment
+      '/' if self.nth(1) != '/' => Some(DocStyle::Outer),
+      '!' => Some(DocStyle::Inner),
+
+      _ => None,
+    };
+    self.consume(|ch| ch != '\n' && ch != EOF_CHAR);
+
+    TokenKind::LineComment { doc_style }
   }
   fn block_comment(&mut self) -> TokenKind {
     debug_assert_eq!(self.step(), '*');
 
+    let doc_style = match self.peek() {
+      // `/***` is an ordinary comment, `/**/` is an empty one
+      '*' if !matches!(self.nth(1), '*' | '/') => Some(DocStyle::Outer),
+      '!' => Some(DocStyle::Inner),
+
+      _ => None,
+    };
+
+    let mut depth = 1usize;
     loop {
+      if self.is_eof() {
+        break TokenKind::BlockComment {
+          doc_style,
+          terminated: false,
+        };
+      };
+
       match self.step() {
-        '*' => match self.peek() {
-          '/' => break TokenKind::BlockComment,
-          _ => continue,
-        },
+        '/' if self.peek() == '*' => {
+          self.step();
+          depth += 1;
+        }
+        '*' if self.peek() == '/' => {
+          self.step();
+          depth -= 1;
+          if depth == 0 {
+            break TokenKind::BlockComment {
+              doc_style,
+              terminated: true,
+            };
+          };
+        }
 
-        _ => continue,
-      }
+        _ => {}
+      };
     }
   }
+  /// `'a` is a lifetime, `'a'` (or `'\n'`, `'1'`, ...) is a char literal
   fn char_literal_or_lifetime(&mut self) -> TokenKind {
-    let mut is_char_literal = false;
-    while !is_whitespace(self.peek()) || self.peek() == ' ' {
-      if self.peek() == '\'' {
-        is_char_literal = true;
-      };
+    if is_ident_start(self.peek()) && self.nth(1) != '\'' {
       self.step();
+      self.consume(is_ident);
 
-      if is_char_literal {
-        break;
+      // `'ab'` is an (invalid) char literal rather than a lifetime
+      if self.peek() != '\'' {
+        return TokenKind::Lifetime;
       };
-    }
+      self.step();
+      return self.literal_with_suffix(LiteralKind::Char { terminated: true }, true);
+    };
 
-    if is_char_literal {
-      return TokenKind::Literal {
-        kind: LiteralKind::Char,
-        suffix_pos: self.current_token_len() as u32,
+    let terminated = self.single_quoted_string();
+    return self.literal_with_suffix(LiteralKind::Char { terminated }, terminated);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::unescape::{self, Mode};
+
+  fn tokenize(src: &str) -> Vec<Token> {
+    super::tokenize(src).collect()
+  }
+
+  #[test]
+  fn string_literals() {
+    let tokens = tokenize(r#""alma \"jemis\"" b"\x7f" b'a'"#);
+    assert_eq!(
+      tokens[0],
+      Token {
+        kind: TokenKind::Literal {
+          kind: LiteralKind::Str { terminated: true },
+          suffix_pos: 16,
+        },
+        len: 16,
+      }
+    );
+    assert_eq!(
+      tokens[2].kind,
+      TokenKind::Literal {
+        kind: LiteralKind::ByteStr { terminated: true },
+        suffix_pos: 7,
+      }
+    );
+    assert_eq!(
+      tokens[4].kind,
+      TokenKind::Literal {
+        kind: LiteralKind::Byte { terminated: true },
+        suffix_pos: 4,
+      }
+    );
+  }
+
+  #[test]
+  fn unterminated_string() {
+    let tokens = tokenize("\"alma\n");
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(
+      tokens[0].kind,
+      TokenKind::Literal {
+        kind: LiteralKind::Str { terminated: false },
+        suffix_pos: 6,
+      }
+    );
+  }
+
+  #[test]
+  fn float_literals() {
+    let float = |src: &str| match tokenize(src).remove(0).kind {
+      TokenKind::Literal {
+        kind: LiteralKind::Float {
+          base,
+          empty_exponent,
+        },
+        suffix_pos,
+      } => Some((base, empty_exponent, suffix_pos)),
+      _ => None,
+    };
+
+    assert_eq!(float("3.14"), Some((Base::Decimal, false, 4)));
+    assert_eq!(float("1e10"), Some((Base::Decimal, false, 4)));
+    assert_eq!(float("2.5E-3q32"), Some((Base::Decimal, false, 6)));
+    assert_eq!(float("1e+"), Some((Base::Decimal, true, 3)));
+    assert_eq!(float("2."), Some((Base::Decimal, false, 2)));
+    assert_eq!(float("0e101.1"), Some((Base::Binary, false, 7)));
+    assert_eq!(float("0s7e1"), Some((Base::Octal, false, 5)));
+
+    // Ranges and field accesses are not floats
+    let kinds = |src: &str| {
+      tokenize(src)
+        .into_iter()
+        .map(|token| token.kind)
+        .collect::<Vec<_>>()
+    };
+    assert_eq!(float("1..2"), None);
+    assert_eq!(kinds("1..2")[1..3], [TokenKind::Dot, TokenKind::Dot]);
+    assert_eq!(float("1.alma"), None);
+    assert_eq!(kinds("1.alma")[1], TokenKind::Dot);
+  }
+
+  #[test]
+  fn int_literals() {
+    let int = |src: &str| match tokenize(src).remove(0).kind {
+      TokenKind::Literal {
+        kind: LiteralKind::Int { base, empty_int },
+        suffix_pos,
+      } => Some((base, empty_int, suffix_pos)),
+      _ => None,
+    };
+
+    assert_eq!(int("0o1F_ff"), Some((Base::Hexadecimal, false, 7)));
+    // `b` is a hexadecimal digit, so an unseparated suffix is a part of the number
+    assert_eq!(int("0o1Fb8"), Some((Base::Hexadecimal, false, 6)));
+    assert_eq!(int("0o1F_b8"), Some((Base::Hexadecimal, false, 5)));
+    assert_eq!(int("0o1F_b8c"), Some((Base::Hexadecimal, false, 8)));
+    assert_eq!(int("0e1010"), Some((Base::Binary, false, 6)));
+    assert_eq!(int("0s777"), Some((Base::Octal, false, 5)));
+    assert_eq!(int("0e129"), Some((Base::Binary, false, 5)));
+    assert_eq!(int("012"), Some((Base::Decimal, false, 3)));
+    assert_eq!(int("0e"), Some((Base::Binary, true, 2)));
+    assert_eq!(int("0o"), Some((Base::Hexadecimal, true, 2)));
+  }
+
+  #[test]
+  fn unicode_idents() {
+    let tokens = tokenize("айнымалы әліпби_2 qazaqşa ұя");
+    let idents = tokens
+      .iter()
+      .filter(|token| token.kind == TokenKind::Ident)
+      .map(|token| token.len)
+      .collect::<Vec<_>>();
+    assert_eq!(
+      idents,
+      [
+        "айнымалы".len(),
+        "әліпби_2".len(),
+        "qazaqşa".len(),
+        "ұя".len()
+      ]
+    );
+
+    assert_eq!(tokenize("😀")[0].kind, TokenKind::Unknown);
+  }
+
+  #[test]
+  fn escapes() {
+    let mut cooked = String::new();
+    unescape::unescape(r#"a\n\t\\\"\0\x41\u{4d9}"#, Mode::Str, &mut |_, ch| {
+      cooked.push(ch.unwrap())
+    });
+    assert_eq!(cooked, "a\n\t\\\"\0Aә");
+
+    let mut errors = Vec::new();
+    unescape::unescape(r"\q\x80\u{D800}", Mode::Str, &mut |range, ch| {
+      if let Err(err) = ch {
+        errors.push((range, err));
       };
-    } else {
-      return TokenKind::Lifetime;
+    });
+    assert_eq!(
+      errors,
+      [
+        (0..2, unescape::EscapeError::InvalidEscape),
+        (2..6, unescape::EscapeError::OutOfRangeHexEscape),
+        (6..14, unescape::EscapeError::LoneSurrogateUnicodeEscape),
+      ]
+    );
+  }
+
+  #[test]
+  fn comments() {
+    let comment = |src: &str| {
+      let tokens = tokenize(src);
+      assert_eq!(tokens[0].len, src.len(), "{src}");
+      tokens[0].kind
+    };
+
+    assert_eq!(
+      comment("// alma"),
+      TokenKind::LineComment { doc_style: None }
+    );
+    assert_eq!(
+      comment("//// alma"),
+      TokenKind::LineComment { doc_style: None }
+    );
+    assert_eq!(
+      comment("/// alma"),
+      TokenKind::LineComment {
+        doc_style: Some(DocStyle::Outer)
+      }
+    );
+    assert_eq!(
+      comment("//! alma"),
+      TokenKind::LineComment {
+        doc_style: Some(DocStyle::Inner)
+      }
+    );
+    assert_eq!(
+      comment("/* a /* b */ c */"),
+      TokenKind::BlockComment {
+        doc_style: None,
+        terminated: true
+      }
+    );
+    assert_eq!(
+      comment("/** a */"),
+      TokenKind::BlockComment {
+        doc_style: Some(DocStyle::Outer),
+        terminated: true
+      }
+    );
+    assert_eq!(
+      comment("/**/"),
+      TokenKind::BlockComment {
+        doc_style: None,
+        terminated: true
+      }
+    );
+    assert_eq!(
+      comment("/* a /* b */"),
+      TokenKind::BlockComment {
+        doc_style: None,
+        terminated: false
+      }
+    );
+  }
+
+  #[test]
+  fn lifetimes() {
+    let kinds = |src: &str| {
+      tokenize(src)
+        .into_iter()
+        .map(|token| token.kind)
+        .filter(|kind| *kind != TokenKind::Whitespace)
+        .collect::<Vec<_>>()
     };
+    let char_lit = TokenKind::Literal {
+      kind: LiteralKind::Char { terminated: true },
+      suffix_pos: 3,
+    };
+
+    assert_eq!(kinds("'a"), [TokenKind::Lifetime]);
+    assert_eq!(
+      kinds("'alma: 'b"),
+      [TokenKind::Lifetime, TokenKind::Colon, TokenKind::Lifetime]
+    );
+    assert_eq!(kinds("'a'"), [char_lit]);
+    assert_eq!(kinds("'1'"), [char_lit]);
+    assert_eq!(
+      kinds("&'a T"),
+      [TokenKind::And, TokenKind::Lifetime, TokenKind::Ident]
+    );
+    assert_eq!(kinds("'ә"), [TokenKind::Lifetime]);
+  }
+
+  #[test]
+  fn raw_idents() {
+    let tokens = tokenize("s#fx s#ә s sa");
+    assert_eq!(
+      tokens
+        .iter()
+        .filter(|token| token.kind != TokenKind::Whitespace)
+        .map(|token| (token.kind, token.len))
+        .collect::<Vec<_>>(),
+      [
+        (TokenKind::RawIdent, 4),
+        (TokenKind::RawIdent, 4),
+        (TokenKind::Ident, 1),
+        (TokenKind::Ident, 2),
+      ]
+    );
+  }
+
+  #[test]
+  fn raw_strings() {
+    let raw_str = |src: &str| {
+      let tokens = tokenize(src);
+      match tokens[0].kind {
+        TokenKind::Literal {
+          kind: LiteralKind::RawStr { n_hashes, err },
+          ..
+        } => (n_hashes, err, tokens[0].len),
+        kind => panic!("{src}: {kind:?}"),
+      }
+    };
+
+    assert_eq!(raw_str(r#"s"a\b""#), (0, None, 6));
+    assert_eq!(raw_str(r##"s#"a"b"#"##), (1, None, 8));
+    assert_eq!(raw_str(r###"s##"a"#b"##"###), (2, None, 11));
+    assert_eq!(
+      raw_str(r####"s##a"b"##"####),
+      (2, Some(RawStrError::InvalidStarter { bad_char: 'a' }), 3)
+    );
+    assert_eq!(
+      raw_str(r####"s##"a"#b"####),
+      (
+        2,
+        Some(RawStrError::NoTerminator {
+          expected: 2,
+          found: 1,
+          possible_terminator_offset: Some(5),
+        }),
+        8
+      )
+    );
+    assert_eq!(
+      raw_str(r###"s#"abc"###),
+      (
+        1,
+        Some(RawStrError::NoTerminator {
+          expected: 1,
+          found: 0,
+          possible_terminator_offset: None,
+        }),
+        6
+      )
+    );
+  }
+
+  #[test]
+  fn lossless() {
+    let src = "fx basty() {\n  /* a /* b */ */ ainymaly x = s#\"q\"# + 'a' ~ 😀 ;\n} // end";
+    let len: usize = tokenize(src).iter().map(|token| token.len).sum();
+    assert_eq!(len, src.len());
   }
 }
@@ -1,5 +1,8 @@
+mod incremental;
 mod lexer;
 mod token;
+pub mod unescape;
 
+pub use incremental::*;
 pub use lexer::*;
 pub use token::*;
@@ -1,4 +1,5 @@
 #[derive(Debug)]
+#[derive(Clone, Copy)]
 #[derive(PartialEq)]
 pub struct Token {
   pub kind: TokenKind,
@@ -6,11 +7,19 @@ pub struct Token {
 }
 
 #[derive(Debug)]
+#[derive(Clone, Copy)]
 #[derive(PartialEq)]
 pub enum TokenKind {
   Whitespace,
-  LineComment,
-  BlockComment,
+  /// `// ...`, or a doc comment (`/// ...`, `//! ...`)
+  LineComment {
+    doc_style: Option<DocStyle>,
+  },
+  /// `/* ... */` (possibly nested), or a doc comment (`/** ... */`, `/*! ... */`)
+  BlockComment {
+    doc_style: Option<DocStyle>,
+    terminated: bool,
+  },
 
   Ident,
   RawIdent,
@@ -82,6 +91,17 @@ pub enum TokenKind {
 }
 
 #[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum DocStyle {
+  /// `///` or `/** */`, documents the following item
+  Outer,
+  /// `//!` or `/*! */`, documents the enclosing item
+  Inner,
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
 #[derive(PartialEq)]
 pub enum Base {
   /// Prefix: "0e"
@@ -94,16 +114,62 @@ pub enum Base {
   Decimal,
 
   /// Prefix: "0o"
+  ///
+  /// Note: suffixes starting with a hexadecimal digit (like `b8`)
+  /// are lexed as a part of the number
   Hexadecimal,
 }
+impl Base {
+  pub const fn radix(self) -> u32 {
+    match self {
+      Self::Binary => 2,
+      Self::Octal => 8,
+      Self::Decimal => 10,
+      Self::Hexadecimal => 16,
+    }
+  }
+}
 #[derive(Debug)]
+#[derive(Clone, Copy)]
 #[derive(PartialEq)]
 pub enum LiteralKind {
-  Int { base: Base },
-  Float { base: Base },
+  /// `empty_int` is set for prefixes without digits like `0e` or `0o`
+  Int { base: Base, empty_int: bool },
+  /// `empty_exponent` is set for literals like `1e` or `2.5E-`
+  Float { base: Base, empty_exponent: bool },
 
-  Char,
-  Str,
-  Byte,
-  RawStr,
+  /// 'a'
+  Char { terminated: bool },
+  /// b'a'
+  Byte { terminated: bool },
+  /// "abc"
+  Str { terminated: bool },
+  /// b"abc"
+  ByteStr { terminated: bool },
+  /// s"abc", s#"abc"#, s##"ab"#c"##
+  ///
+  /// `n_hashes` is the number of `#` in the opening delimiter
+  RawStr {
+    n_hashes: u32,
+    err: Option<RawStrError>,
+  },
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum RawStrError {
+  /// `s##a"..."##`, only `#` may appear between `s` and `"`
+  InvalidStarter { bad_char: char },
+  /// The closing `"` followed by `expected` hashes was never found
+  ///
+  /// `found` is the longest run of hashes after a `"` in the literal, and
+  /// `possible_terminator_offset` is the offset of the `"` starting that run
+  NoTerminator {
+    expected: u32,
+    found: u32,
+    possible_terminator_offset: Option<u32>,
+  },
+  /// More than 255 hashes
+  TooManyDelimiters { found: u32 },
 }
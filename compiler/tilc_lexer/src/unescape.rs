@@ -0,0 +1,235 @@
+use std::{ops::Range, str::Chars};
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum Mode {
+  Char,
+  Byte,
+  Str,
+  ByteStr,
+}
+impl Mode {
+  pub const fn is_byte(self) -> bool {
+    matches!(self, Self::Byte | Self::ByteStr)
+  }
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum EscapeError {
+  /// ''
+  ZeroChars,
+  /// 'ab'
+  MoreThanOneChar,
+
+  /// '\'
+  LoneSlash,
+  /// '\q'
+  InvalidEscape,
+  /// '\r' written as is
+  BareCarriageReturn,
+  /// ''' or '\t' written as is
+  EscapeOnlyChar,
+
+  /// '\x1'
+  TooShortHexEscape,
+  /// '\xz1'
+  InvalidCharInHexEscape,
+  /// '\x80' outside of byte literals
+  OutOfRangeHexEscape,
+
+  /// '\u1'
+  NoBraceInUnicodeEscape,
+  /// '\u{z}'
+  InvalidCharInUnicodeEscape,
+  /// '\u{}'
+  EmptyUnicodeEscape,
+  /// '\u{1'
+  UnclosedUnicodeEscape,
+  /// '\u{_1}'
+  LeadingUnderscoreUnicodeEscape,
+  /// '\u{1234567}'
+  OverlongUnicodeEscape,
+  /// '\u{D800}'
+  LoneSurrogateUnicodeEscape,
+  /// '\u{FFFFFF}'
+  OutOfRangeUnicodeEscape,
+
+  /// b'\u{1}'
+  UnicodeEscapeInByte,
+  /// b'ә'
+  NonAsciiCharInByte,
+}
+impl EscapeError {
+  pub const fn message(self) -> &'static str {
+    match self {
+      Self::ZeroChars => "empty character literal",
+      Self::MoreThanOneChar => "character literal may only contain one codepoint",
+      Self::LoneSlash => "unescaped backslash at the end of the literal",
+      Self::InvalidEscape => "unknown character escape",
+      Self::BareCarriageReturn => "bare CR not allowed in literal",
+      Self::EscapeOnlyChar => "character must be escaped",
+      Self::TooShortHexEscape => "numeric character escape is too short",
+      Self::InvalidCharInHexEscape => "invalid character in numeric character escape",
+      Self::OutOfRangeHexEscape => "out of range hex escape, must be at most `\\x7f`",
+      Self::NoBraceInUnicodeEscape => "incorrect unicode escape sequence, expected `\\u{...}`",
+      Self::InvalidCharInUnicodeEscape => "invalid character in unicode escape",
+      Self::EmptyUnicodeEscape => "empty unicode escape",
+      Self::UnclosedUnicodeEscape => "unterminated unicode escape",
+      Self::LeadingUnderscoreUnicodeEscape => "invalid start of unicode escape: `_`",
+      Self::OverlongUnicodeEscape => "overlong unicode escape, must have at most 6 hex digits",
+      Self::LoneSurrogateUnicodeEscape => {
+        "invalid unicode character escape, must not be a surrogate"
+      }
+      Self::OutOfRangeUnicodeEscape => "invalid unicode character escape, must be at most 10FFFF",
+      Self::UnicodeEscapeInByte => "unicode escape in byte literal",
+      Self::NonAsciiCharInByte => "non-ASCII character in byte literal",
+    }
+  }
+}
+
+/// Unescapes the contents of a literal (without quotes and prefix) and calls `callback`
+/// with the byte range of every produced character
+///
+/// For byte modes every produced `char` is in `0..=255` and represents exactly one byte
+pub fn unescape<F>(src: &str, mode: Mode, callback: &mut F)
+where
+  F: FnMut(Range<usize>, Result<char, EscapeError>), {
+  match mode {
+    Mode::Char | Mode::Byte => {
+      let mut chars = src.chars();
+      let result = unescape_single(&mut chars, mode);
+      callback(0..(src.len() - chars.as_str().len()), result);
+    }
+    Mode::Str | Mode::ByteStr => unescape_str(src, mode, callback),
+  };
+}
+
+fn unescape_single(chars: &mut Chars<'_>, mode: Mode) -> Result<char, EscapeError> {
+  let ch = chars.next().ok_or(EscapeError::ZeroChars)?;
+  let result = match ch {
+    '\\' => scan_escape(chars, mode),
+    '\n' | '\t' | '\'' => Err(EscapeError::EscapeOnlyChar),
+    '\r' => Err(EscapeError::BareCarriageReturn),
+
+    _ => check_char(ch, mode),
+  }?;
+
+  if chars.next().is_some() {
+    return Err(EscapeError::MoreThanOneChar);
+  };
+  return Ok(result);
+}
+
+fn unescape_str<F>(src: &str, mode: Mode, callback: &mut F)
+where
+  F: FnMut(Range<usize>, Result<char, EscapeError>), {
+  let mut chars = src.chars();
+
+  while let Some(ch) = chars.next() {
+    let start = src.len() - chars.as_str().len() - ch.len_utf8();
+    let result = match ch {
+      '\\' => match chars.clone().next() {
+        Some('\n') => {
+          // Line continuation: skip the newline and the leading whitespace of the next line
+          skip_ascii_whitespace(&mut chars);
+          continue;
+        }
+
+        _ => scan_escape(&mut chars, mode),
+      },
+      '\r' if chars.clone().next() != Some('\n') => Err(EscapeError::BareCarriageReturn),
+
+      _ => check_char(ch, mode),
+    };
+    let end = src.len() - chars.as_str().len();
+    callback(start..end, result);
+  }
+}
+
+fn scan_escape(chars: &mut Chars<'_>, mode: Mode) -> Result<char, EscapeError> {
+  let ch = match chars.next().ok_or(EscapeError::LoneSlash)? {
+    '"' => '"',
+    'n' => '\n',
+    'r' => '\r',
+    't' => '\t',
+    '\\' => '\\',
+    '\'' => '\'',
+    '0' => '\0',
+
+    'x' => {
+      let hi = chars.next().ok_or(EscapeError::TooShortHexEscape)?;
+      let hi = hi.to_digit(16).ok_or(EscapeError::InvalidCharInHexEscape)?;
+      let lo = chars.next().ok_or(EscapeError::TooShortHexEscape)?;
+      let lo = lo.to_digit(16).ok_or(EscapeError::InvalidCharInHexEscape)?;
+
+      let value = hi * 16 + lo;
+      if !mode.is_byte() && value > 0x7F {
+        return Err(EscapeError::OutOfRangeHexEscape);
+      };
+      char::from_u32(value).unwrap_or_else(|| unreachable!())
+    }
+
+    'u' => {
+      let ch = scan_unicode(chars)?;
+      if mode.is_byte() {
+        return Err(EscapeError::UnicodeEscapeInByte);
+      };
+      ch
+    }
+
+    _ => return Err(EscapeError::InvalidEscape),
+  };
+
+  return Ok(ch);
+}
+
+fn scan_unicode(chars: &mut Chars<'_>) -> Result<char, EscapeError> {
+  if chars.next() != Some('{') {
+    return Err(EscapeError::NoBraceInUnicodeEscape);
+  };
+
+  let mut n_digits = 0;
+  let mut value: u32 = 0;
+  loop {
+    match chars.next() {
+      None => return Err(EscapeError::UnclosedUnicodeEscape),
+      Some('_') if n_digits == 0 => return Err(EscapeError::LeadingUnderscoreUnicodeEscape),
+      Some('_') => continue,
+      Some('}') if n_digits == 0 => return Err(EscapeError::EmptyUnicodeEscape),
+      Some('}') => break,
+
+      Some(ch) => {
+        let digit = ch
+          .to_digit(16)
+          .ok_or(EscapeError::InvalidCharInUnicodeEscape)?;
+        n_digits += 1;
+        if n_digits > 6 {
+          return Err(EscapeError::OverlongUnicodeEscape);
+        };
+        value = value * 16 + digit;
+      }
+    };
+  }
+
+  return char::from_u32(value).ok_or(if value > 0x10FFFF {
+    EscapeError::OutOfRangeUnicodeEscape
+  } else {
+    EscapeError::LoneSurrogateUnicodeEscape
+  });
+}
+
+fn check_char(ch: char, mode: Mode) -> Result<char, EscapeError> {
+  if mode.is_byte() && !ch.is_ascii() {
+    return Err(EscapeError::NonAsciiCharInByte);
+  };
+  return Ok(ch);
+}
+
+fn skip_ascii_whitespace(chars: &mut Chars<'_>) {
+  let rest = chars.as_str();
+  let skipped = rest.len() - rest.trim_start_matches([' ', '\t', '\n', '\r']).len();
+  *chars = rest[skipped..].chars();
+}
@@ -15,16 +15,27 @@ use syn::{
   punctuated::Punctuated,
 };
 
+/// `Name: "latin"` or `Name: "latin" | "cyrillic"`
 struct Keyword {
   name: Ident,
   value: LitStr,
+  cyrillic: Option<LitStr>,
 }
 impl Parse for Keyword {
   fn parse(input: ParseStream) -> Result<Self> {
     let name = input.parse()?;
     input.parse::<Token![:]>()?;
     let value = input.parse()?;
-    Ok(Self { name, value })
+    let cyrillic = if input.parse::<Option<Token![|]>>()?.is_some() {
+      Some(input.parse()?)
+    } else {
+      None
+    };
+    Ok(Self {
+      name,
+      value,
+      cyrillic,
+    })
   }
 }
 struct Symbol {
@@ -91,6 +102,7 @@ pub(super) fn symbols(input: TokenStream) -> TokenStream {
   let input = parse_macro_input!(input as Input);
 
   let mut keywords = quote! {};
+  let mut cyrillic_keywords = quote! {};
   let mut symbols = quote! {};
   let mut interner_prefill = quote! {};
   let mut entries = Entries::with_capacity(input.keywords.len() + input.symbols.len() + 1);
@@ -108,6 +120,23 @@ pub(super) fn symbols(input: TokenStream) -> TokenStream {
       pub const #name: Symbol = Symbol::new(#idx);
     });
   }
+  // Cyrillic spellings are interned right after the latin ones,
+  // so both tables are contiguous
+  for keyword in input.keywords.iter() {
+    let Some(cyrillic) = &keyword.cyrillic else {
+      continue;
+    };
+    let name = &keyword.name;
+    let idx = entries.insert(&cyrillic.value(), cyrillic.span());
+
+    interner_prefill.extend(quote! {
+      #cyrillic,
+    });
+
+    cyrillic_keywords.extend(quote! {
+      (kw::#name, Symbol::new(#idx)),
+    });
+  }
   for symbol in input.symbols.iter() {
     let name = &symbol.name;
     let value = &symbol.value;
@@ -131,6 +160,12 @@ pub(super) fn symbols(input: TokenStream) -> TokenStream {
       use crate::Symbol;
       #keywords
     }
+    /// Pairs of latin and cyrillic spellings of keywords
+    pub(crate) const CYRILLIC_KEYWORDS: &[(crate::Symbol, crate::Symbol)] = {
+      use crate::Symbol;
+      &[#cyrillic_keywords]
+    };
+
     #[allow(non_upper_case_globals)]
     pub mod sym {
       use crate::Symbol;
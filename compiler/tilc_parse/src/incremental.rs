@@ -0,0 +1,223 @@
+use tilc_advanced_lexer::{LosslessToken, LosslessTokenKind, TokenReader};
+use tilc_ast::{TokenKind, TokenStream};
+use tilc_lexer::{Lexer, TextEdit};
+use tilc_session::ParseSession;
+use tilc_span::{BytePos, KeywordScript, Pos, Span, SpanCtxt};
+
+use crate::{file_keyword_script, lex_all_token_trees};
+
+/// Every token of `src` including trivia (without the final `Eof`), lexed with the keywords of
+/// `kw_script` (see [`file_keyword_script`])
+pub fn lex_lossless_tokens(
+  psess: &ParseSession,
+  src: &str,
+  start_pos: BytePos,
+  kw_script: KeywordScript,
+) -> Vec<LosslessToken> {
+  let mut token_reader =
+    TokenReader::new(src, Lexer::new(src), psess, kw_script, start_pos, start_pos);
+
+  let mut tokens = Vec::new();
+  loop {
+    let token = token_reader.next_lossless_token();
+    if token.kind == LosslessTokenKind::Token(TokenKind::Eof) {
+      break;
+    };
+    tokens.push(token);
+  }
+
+  return tokens;
+}
+
+/// Updates the tokens of a file after the `edit`, `new_src` is the edited source and
+/// `kw_script` is the script the old tokens were lexed with
+///
+/// Same as [`tilc_lexer::relex`], but for cooked tokens: the tokens after the re-lexed range
+/// are reused with their spans shifted by the size change of the edit. Only the diagnostics of
+/// the re-lexed range are reported, the reused tokens were already reported when they were
+/// lexed (pass the result to [`lossless_tokens_to_stream`] to get the stream for the parser).
+///
+/// The header of `new_src` is scanned again, if the edit changes `#![kilt_sozder]` every old
+/// token is thrown away and the whole file is lexed with the new script, which is returned
+pub fn relex_lossless_tokens(
+  psess: &ParseSession,
+  old_tokens: &[LosslessToken],
+  kw_script: KeywordScript,
+  new_src: &str,
+  start_pos: BytePos,
+  edit: &TextEdit,
+) -> (Vec<LosslessToken>, KeywordScript) {
+  let new_kw_script = file_keyword_script(new_src, psess.edition());
+  if new_kw_script != kw_script {
+    let tokens = lex_lossless_tokens(psess, new_src, start_pos, new_kw_script);
+    return (tokens, new_kw_script);
+  };
+
+  let offset = |pos: BytePos| (pos - start_pos).to_usize();
+
+  let restart_idx = edit.restart_idx(old_tokens.iter().map(|token| offset(token.span.hi())));
+  let restart = match restart_idx {
+    0 => 0,
+    _ => offset(old_tokens[restart_idx - 1].span.hi()),
+  };
+
+  let mut tokens = old_tokens[..restart_idx].to_vec();
+  let restart_pos = start_pos + BytePos::from_usize(restart);
+  let scratch = psess.scratch();
+  let mut token_reader = TokenReader::new(
+    new_src,
+    Lexer::new(&new_src[restart..]),
+    &scratch,
+    kw_script,
+    start_pos,
+    restart_pos,
+  );
+
+  let mut old_idx = restart_idx;
+  loop {
+    let token = token_reader.next_lossless_token();
+    if token.kind == LosslessTokenKind::Token(TokenKind::Eof) {
+      old_idx = old_tokens.len();
+      break;
+    };
+    tokens.push(token);
+
+    let pos = offset(token.span.hi());
+    if pos < edit.new_end() {
+      continue;
+    };
+    let old_equiv = edit.unshift(pos);
+    while old_idx < old_tokens.len() && offset(old_tokens[old_idx].span.lo()) < old_equiv {
+      old_idx += 1;
+    }
+    let synced = match old_tokens.get(old_idx) {
+      Some(old_token) => offset(old_token.span.lo()) == old_equiv,
+      None => false,
+    };
+    if synced {
+      break;
+    };
+  }
+
+  tokens.extend(old_tokens[old_idx..].iter().map(|token| {
+    let lo = start_pos + BytePos::from_usize(edit.shift(offset(token.span.lo())));
+    let hi = start_pos + BytePos::from_usize(edit.shift(offset(token.span.hi())));
+    LosslessToken {
+      kind: token.kind,
+      span: Span::new(lo, hi, SpanCtxt::ROOT, None),
+    }
+  }));
+
+  for diag in scratch.dcx().take_emitted() {
+    psess.dcx().emit_diagnostic(diag);
+  }
+  return (tokens, kw_script);
+}
+
+/// Token trees of the file made of `tokens`, unbalanced delimiters are reported
+pub fn lossless_tokens_to_stream(
+  psess: &ParseSession,
+  src: &str,
+  start_pos: BytePos,
+  tokens: &[LosslessToken],
+) -> TokenStream {
+  let (stream, _) = lex_all_token_trees(psess, src, start_pos, tokens.iter().copied());
+  return stream;
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{lex_token_stream, tests_util::new_psess};
+
+  /// `src` after replacing `range` with `new_text`
+  fn edit(src: &str, range: std::ops::Range<usize>, new_text: &str) -> (TextEdit, String) {
+    let edit = TextEdit {
+      range,
+      new_text: new_text.to_string(),
+    };
+    let new_src = edit.apply(src);
+    return (edit, new_src);
+  }
+
+  #[test]
+  fn relex_matches_full_lex() {
+    let psess = new_psess();
+    let start_pos = BytePos::from_u32(0);
+
+    let src =
+      "/// Basty\nfx basty() {\n  ainymaly alma = 'a';\n  qaitar s#\"q\"#;\n}\nfx b() { ras; }\n";
+    let old_tokens = lex_lossless_tokens(&psess, src, start_pos, KeywordScript::Latin);
+
+    let edits = [
+      (31..35, "almurt"),
+      (14..14, "/* "),
+      (44..44, "b'"),
+      (0..3, ""),
+      (0..0, "#![kilt_sozder = \"kirill\"]\n"),
+    ];
+    for (range, new_text) in edits {
+      let (edit, new_src) = edit(src, range, new_text);
+
+      let err_count = psess.dcx().err_count();
+      let (relexed, kw_script) = relex_lossless_tokens(
+        &psess,
+        &old_tokens,
+        KeywordScript::Latin,
+        &new_src,
+        start_pos,
+        &edit,
+      );
+      assert_eq!(kw_script, file_keyword_script(&new_src, psess.edition()));
+
+      let reference = psess.scratch();
+      assert_eq!(
+        relexed,
+        lex_lossless_tokens(&reference, &new_src, start_pos, kw_script),
+        "{new_src}"
+      );
+      // Only the errors of the re-lexed range are reported, here it covers every error
+      assert_eq!(
+        psess.dcx().err_count() - err_count,
+        reference.dcx().err_count(),
+        "{new_src}"
+      );
+      assert_eq!(
+        format!(
+          "{:?}",
+          lossless_tokens_to_stream(&reference, &new_src, start_pos, &relexed)
+        ),
+        format!(
+          "{:?}",
+          lex_token_stream(&reference, &new_src, start_pos, kw_script).unwrap()
+        ),
+        "{new_src}"
+      );
+    }
+  }
+
+  #[test]
+  fn relex_reports_new_errors() {
+    let src = "fx basty() {\n  ainymaly alma = 'a';\n}\n";
+    let relex_errors = |range, new_text| {
+      let psess = new_psess();
+      let start_pos = BytePos::from_u32(0);
+      let old_tokens = lex_lossless_tokens(&psess, src, start_pos, KeywordScript::Latin);
+
+      let (edit, new_src) = edit(src, range, new_text);
+      let _ = relex_lossless_tokens(
+        &psess,
+        &old_tokens,
+        KeywordScript::Latin,
+        &new_src,
+        start_pos,
+        &edit,
+      );
+      return psess.dcx().err_count();
+    };
+
+    assert_eq!(relex_errors(31..34, "\"a"), 1);
+    assert_eq!(relex_errors(15..15, "№"), 1);
+    assert_eq!(relex_errors(24..28, "almurt"), 0);
+  }
+}
@@ -0,0 +1,276 @@
+use std::path::{Path, PathBuf};
+
+use tilc_ast::{AttrArgs, Attribute, ExprKind, Item, ItemKind, Korpe, Lit, LitKind, Sandyq};
+use tilc_session::ParseSession;
+use tilc_span::{Ident, Span, sym};
+
+use crate::new_source_from_source_file;
+
+/// Loads the files of every `korpe a;` in the sandyq, recursively.
+///
+/// `korpe a;` is looked for in `a.til` or `a/mod.til` next to the sandyq root or a `mod.til`,
+/// and in `b/a.til` or `b/a/mod.til` when declared in `b.til`. `#[path = "..."]` overrides the
+/// file, relative to the directory of the declaring file (or of the inline korpe)
+pub fn load_korpe_files(psess: &ParseSession, sandyq: &mut Sandyq, root_path: &Path) {
+  let dir = root_path
+    .parent()
+    .map(Path::to_path_buf)
+    .unwrap_or_default();
+
+  let mut loader = KorpeLoader {
+    psess,
+    file_stack: vec![
+      root_path
+        .canonicalize()
+        .unwrap_or_else(|_| root_path.to_path_buf()),
+    ],
+  };
+  loader.load_items(&mut sandyq.items, &dir, &dir);
+}
+
+struct KorpeLoader<'psess> {
+  psess: &'psess ParseSession,
+  /// Files being loaded, to report `korpe a;` that includes itself
+  file_stack: Vec<PathBuf>,
+}
+impl KorpeLoader<'_> {
+  /// `dir` is where the file korpes declared in `items` are looked for,
+  /// `path_dir` is what their `#[path]` is relative to
+  fn load_items(&mut self, items: &mut [Item], dir: &Path, path_dir: &Path) {
+    for item in items {
+      let Item {
+        attrs, kind, span, ..
+      } = item;
+      let ItemKind::Korpe(_, ident, korpe) = kind else {
+        continue;
+      };
+
+      match korpe {
+        Korpe::Braced(items, _) => {
+          let dir = match self.path_attr(attrs) {
+            Some(path) => dir.join(path),
+            None => dir.join(ident.name.as_str()),
+          };
+          self.load_items(items, &dir, &dir);
+        }
+        Korpe::File => {
+          let Some((path, korpe_dir)) = self.korpe_file_path(attrs, *ident, *span, dir, path_dir)
+          else {
+            continue;
+          };
+          let Some((sandyq, canonical_path)) = self.load_file(&path, *span) else {
+            continue;
+          };
+          let file_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+          let Sandyq {
+            attrs: inner_attrs,
+            mut items,
+            span: file_span,
+            ..
+          } = sandyq;
+          self.file_stack.push(canonical_path);
+          self.load_items(&mut items, &korpe_dir, &file_dir);
+          self.file_stack.pop();
+
+          attrs.extend(inner_attrs);
+          *korpe = Korpe::Loaded(items, file_span);
+        }
+        Korpe::Loaded(..) => {}
+      };
+    }
+  }
+
+  /// File of the korpe and the directory of its own file korpes
+  fn korpe_file_path(
+    &self,
+    attrs: &[Attribute],
+    ident: Ident,
+    span: Span,
+    dir: &Path,
+    path_dir: &Path,
+  ) -> Option<(PathBuf, PathBuf)> {
+    if let Some(path) = self.path_attr(attrs) {
+      let path = path_dir.join(path);
+      let korpe_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+      return Some((path, korpe_dir));
+    };
+
+    let name = ident.name.as_str();
+    let default_path = dir.join(format!("{name}.til"));
+    let secondary_path = dir.join(name).join("mod.til");
+
+    match (default_path.is_file(), secondary_path.is_file()) {
+      (true, false) => return Some((default_path, dir.join(name))),
+      (false, true) => return Some((secondary_path, dir.join(name))),
+
+      (true, true) => {
+        self
+          .psess
+          .dcx()
+          .struct_span_err(
+            span,
+            format!(
+              "file for korpe `{name}` found at both \"{}\" and \"{}\"",
+              default_path.display(),
+              secondary_path.display(),
+            ),
+          )
+          .with_help("delete or rename one of them to remove the ambiguity")
+          .emit();
+      }
+      (false, false) => {
+        self
+          .psess
+          .dcx()
+          .struct_span_err(span, format!("file not found for korpe `{name}`"))
+          .with_help(format!(
+            "to create the korpe `{name}`, create file \"{}\" or \"{}\"",
+            default_path.display(),
+            secondary_path.display(),
+          ))
+          .emit();
+      }
+    };
+
+    return None;
+  }
+  /// `#[path = "a.til"]`
+  fn path_attr(&self, attrs: &[Attribute]) -> Option<String> {
+    let attr = attrs.iter().find(|attr| attr.path.is_ident(sym::Path))?;
+
+    if let AttrArgs::Eq { expr, .. } = &attr.args
+      && let ExprKind::Lit(Lit {
+        kind: LitKind::Str,
+        symbol,
+        ..
+      }) = expr.kind
+    {
+      return Some(symbol.as_str().to_string());
+    };
+
+    self
+      .psess
+      .dcx()
+      .struct_span_err(attr.span, "malformed `path` attribute")
+      .with_help("use `#[path = \"file.til\"]`")
+      .emit();
+    return None;
+  }
+  /// The parsed file and its canonical path
+  fn load_file(&self, path: &Path, span: Span) -> Option<(Sandyq, PathBuf)> {
+    let canonical_path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    if let Some(idx) = self
+      .file_stack
+      .iter()
+      .position(|file| *file == canonical_path)
+    {
+      let cycle = self.file_stack[idx..]
+        .iter()
+        .chain([&canonical_path])
+        .map(|file| file.display().to_string())
+        .collect::<Vec<_>>()
+        .join(" -> ");
+      self
+        .psess
+        .dcx()
+        .struct_span_err(span, format!("circular korpes: {cycle}"))
+        .emit();
+      return None;
+    };
+
+    let source_file = match self.psess.source_map().load_file(path) {
+      Ok(source_file) => source_file,
+      Err(err) => {
+        self
+          .psess
+          .dcx()
+          .struct_span_err(span, format!("couldn't read \"{}\": {err}", path.display()))
+          .emit();
+        return None;
+      }
+    };
+
+    let sandyq = new_source_from_source_file(self.psess, source_file)
+      .ok()?
+      .parse_sandyq()
+      .map_err(|diag| diag.emit())
+      .ok()?;
+    return Some((sandyq, canonical_path));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::fs;
+
+  use super::*;
+  use crate::{new_parser_from_file, tests_util::new_psess};
+
+  /// Writes `files` into a fresh directory and loads the korpes of its `main.til`,
+  /// returning the items of the root and the number of reported errors
+  fn load(name: &str, files: &[(&str, &str)]) -> (Vec<Item>, usize) {
+    let dir = std::env::temp_dir().join(format!("tilc_korpe_{name}_{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    for (path, src) in files {
+      let path = dir.join(path);
+      fs::create_dir_all(path.parent().unwrap()).unwrap();
+      fs::write(path, src).unwrap();
+    }
+
+    let psess = new_psess();
+    let root_path = dir.join("main.til");
+    let mut parser = new_parser_from_file(&psess, &root_path).unwrap();
+    let mut sandyq = parser.parse_sandyq().unwrap();
+    load_korpe_files(&psess, &mut sandyq, &root_path);
+
+    let _ = fs::remove_dir_all(&dir);
+    return (sandyq.items, psess.dcx().err_count());
+  }
+  fn korpe_items(item: &Item) -> &[Item] {
+    match &item.kind {
+      ItemKind::Korpe(_, _, Korpe::Braced(items, _) | Korpe::Loaded(items, _)) => items,
+      _ => panic!("expected a loaded korpe"),
+    }
+  }
+
+  #[test]
+  fn file_korpes() {
+    let (items, errors) = load(
+      "found",
+      &[
+        (
+          "main.til",
+          "korpe a; korpe b; korpe c { korpe d; } #[path = \"x/y.til\"] korpe e;",
+        ),
+        ("a.til", "#![doc = \"a\"] korpe f; fx g() {}"),
+        ("a/f.til", "fx h() {}"),
+        ("b/mod.til", "korpe f;"),
+        ("b/f.til", "fx h() {}"),
+        ("c/d.til", "fx h() {}"),
+        ("x/y.til", "fx h() {}"),
+      ],
+    );
+    assert_eq!(errors, 0);
+    assert_eq!(items[0].attrs.len(), 1);
+    assert_eq!(korpe_items(&korpe_items(&items[0])[0]).len(), 1);
+    assert_eq!(korpe_items(&korpe_items(&items[1])[0]).len(), 1);
+    assert_eq!(korpe_items(&korpe_items(&items[2])[0]).len(), 1);
+    assert_eq!(korpe_items(&items[3]).len(), 1);
+  }
+
+  #[test]
+  fn missing_and_ambiguous() {
+    let (items, errors) = load(
+      "errors",
+      &[
+        ("main.til", "korpe a; korpe b; korpe c;"),
+        ("a.til", ""),
+        ("a/mod.til", ""),
+        ("c.til", "#[path = \"main.til\"] korpe d;"),
+      ],
+    );
+    assert_eq!(errors, 3);
+    assert!(matches!(items[0].kind, ItemKind::Korpe(_, _, Korpe::File)));
+  }
+}
@@ -1,5 +1,18 @@
+mod incremental;
+mod korpe;
+mod literals;
+mod migrate;
+mod non_ascii_idents;
 mod passes;
 mod token_trees;
 
+#[cfg(test)]
+mod tests_util;
+
+pub use incremental::*;
+pub use korpe::*;
+pub use literals::*;
+pub use migrate::*;
+pub use non_ascii_idents::*;
 pub use passes::*;
 pub use token_trees::*;
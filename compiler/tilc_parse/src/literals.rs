@@ -0,0 +1,142 @@
+use tilc_ast::{
+  Expr, ExprKind, FloatTy, IntTy, LitError, LitIntType, LitValue, Sandyq, UintTy, UnOp,
+  visit::{self, Visitor},
+};
+use tilc_session::ParseSession;
+use tilc_span::Span;
+
+/// Parses every literal of the sandyq into its value, reporting invalid suffixes and
+/// values that don't fit into their suffixed type (`300b8`)
+pub fn check_literals(psess: &ParseSession, sandyq: &Sandyq) {
+  LiteralChecker { psess }.visit_sandyq(sandyq);
+}
+
+struct LiteralChecker<'psess> {
+  psess: &'psess ParseSession,
+}
+impl LiteralChecker<'_> {
+  fn check_lit(&self, expr: &Expr, negated: bool) {
+    let ExprKind::Lit(lit) = &expr.kind else {
+      return;
+    };
+
+    match lit.value() {
+      Ok(LitValue::Int(value, ty)) => {
+        self.check_int_range(expr.span, lit.symbol.as_str(), value, ty, negated)
+      }
+      Ok(_) => {}
+
+      Err(err) => self.report_lit_error(expr.span, err),
+    };
+  }
+
+  fn check_int_range(&self, span: Span, text: &str, value: u128, ty: LitIntType, negated: bool) {
+    let (name, min, max, fits) = match ty {
+      LitIntType::Signed(ty) => {
+        let limit = if negated { ty.max() + 1 } else { ty.max() };
+        (
+          ty.name(),
+          format!("-{}", ty.max() + 1),
+          ty.max().to_string(),
+          value <= limit,
+        )
+      }
+      LitIntType::Unsigned(ty) => (
+        ty.name(),
+        String::from("0"),
+        ty.max().to_string(),
+        value <= ty.max(),
+      ),
+      LitIntType::Unsuffixed => return,
+    };
+    if fits {
+      return;
+    };
+
+    let sign = if negated { "-" } else { "" };
+    self
+      .psess
+      .dcx()
+      .struct_span_err(span, format!("literal out of range for `{}`", name.as_str()))
+      .with_label(span, "this literal doesn't fit")
+      .with_note(format!(
+        "the literal `{sign}{text}{}` does not fit into the type `{}` whose range is `{min}..={max}`",
+        name.as_str(),
+        name.as_str(),
+      ))
+      .emit();
+  }
+
+  fn report_lit_error(&self, span: Span, err: LitError) {
+    let diag = self.psess.dcx().struct_span_err(span, err.to_string());
+    let diag = match err {
+      LitError::InvalidIntSuffix(_) => {
+        let valid = IntTy::ALL
+          .into_iter()
+          .map(IntTy::name)
+          .chain(UintTy::ALL.into_iter().map(UintTy::name))
+          .chain(FloatTy::ALL.into_iter().map(FloatTy::name))
+          .map(|name| format!("`{}`", name.as_str()))
+          .collect::<Vec<_>>();
+        diag.with_help(format!("the suffix must be one of {}", valid.join(", ")))
+      }
+      LitError::InvalidFloatSuffix(_) => diag.with_help("valid suffixes are `q32` and `q64`"),
+      LitError::InvalidSuffix(_) => diag.with_help("only number literals can have a suffix"),
+      LitError::IntTooLarge => diag.with_note(format!("value exceeds limit of `{}`", u128::MAX)),
+      LitError::FloatTooLarge => diag,
+    };
+    diag.emit();
+  }
+}
+impl Visitor for LiteralChecker<'_> {
+  fn visit_expr(&mut self, expr: &Expr) {
+    match &expr.kind {
+      // `-128b8` fits, but `128b8` doesn't
+      ExprKind::Unary(UnOp::Neg, lit) if matches!(lit.kind, ExprKind::Lit(_)) => {
+        self.check_lit(lit, true);
+        expr
+          .attrs
+          .iter()
+          .for_each(|attr| self.visit_attribute(attr));
+      }
+
+      _ => {
+        self.check_lit(expr, false);
+        visit::walk_expr(self, expr);
+      }
+    };
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::tests_util::{new_psess, parse_sandyq};
+
+  /// Number of errors reported for the source
+  fn check(src: &str) -> usize {
+    let psess = new_psess();
+    let sandyq = parse_sandyq(&psess, src);
+    check_literals(&psess, &sandyq);
+
+    return psess.dcx().err_count();
+  }
+
+  #[test]
+  fn out_of_range() {
+    assert_eq!(check("fx f() { ainymaly a = 127b8; }"), 0);
+    assert_eq!(check("fx f() { ainymaly a = 300b8; }"), 1);
+    assert_eq!(check("fx f() { ainymaly a = -128b8; }"), 0);
+    assert_eq!(check("fx f() { ainymaly a = 128b8; }"), 1);
+    assert_eq!(check("fx f() { ainymaly a = -(129b8); }"), 1);
+    assert_eq!(check("fx f() { ainymaly a = 0o_ffn8; }"), 0);
+    assert_eq!(check("fx f() { ainymaly a = 0o100n8; }"), 1);
+  }
+
+  #[test]
+  fn invalid_suffix() {
+    assert_eq!(check("fx f() { ainymaly a = 1b33; }"), 1);
+    assert_eq!(check("fx f() { ainymaly a = 1.5b8; }"), 1);
+    assert_eq!(check("fx f() { ainymaly a = 1q32; }"), 0);
+  }
+}
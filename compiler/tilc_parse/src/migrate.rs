@@ -0,0 +1,217 @@
+use tilc_error::ErrorGuaranteed;
+use tilc_lexer::{DocStyle, TokenKind};
+use tilc_session::ParseSession;
+use tilc_span::{BytePos, Edition, Ident, KeywordScript, Pos, Span, SpanCtxt, Symbol, sym};
+
+/// Rewrites the keywords of `src` into the `to` script
+///
+/// The current script is taken from `#![kilt_sozder = "..."]` (or from the edition if the
+/// attribute is missing). Identifiers that are keywords in the `to` script become raw
+/// identifiers, and the attribute is updated (or inserted) to match the new script.
+/// Identifiers that would have to become `s#ozi`, `s#super`, etc. are reported instead,
+/// since those cannot be raw identifiers
+pub fn migrate_keyword_script(
+  psess: &ParseSession,
+  src: &str,
+  start_pos: BytePos,
+  to: KeywordScript,
+) -> Result<String, ErrorGuaranteed> {
+  let tokens = tokenize(src).collect::<Vec<_>>();
+  let attr_value = find_keyword_script_attr(tokens.iter().copied());
+  let from = script_from_attr(attr_value, psess.edition());
+
+  let mut migrated = String::with_capacity(src.len());
+  if attr_value.is_none() {
+    migrated.push_str(&format!("#![kilt_sozder = \"{}\"]\n", script_name(to)));
+  };
+
+  let mut pos = start_pos;
+  for (idx, &(kind, text)) in tokens.iter().enumerate() {
+    let lo = pos;
+    pos = pos + BytePos::from_usize(text.len());
+
+    match kind {
+      TokenKind::Ident => {
+        let symbol = Symbol::intern(text);
+        if let Some(kw) = symbol.kw_from(from) {
+          migrated.push_str(kw.kw_in(to).as_str());
+        } else if symbol.kw_from(to).is_some() {
+          let ident = Ident {
+            name: symbol,
+            span: Span::new(lo, pos, SpanCtxt::ROOT, None),
+          };
+          if !ident.can_be_raw(to) {
+            psess
+              .dcx()
+              .struct_span_err(
+                ident.span,
+                format!(
+                  "`{text}` is a keyword in the {} script and cannot be a raw identifier",
+                  script_name(to),
+                ),
+              )
+              .with_help(format!("rename `{text}` before migrating"))
+              .emit();
+          };
+          migrated.push_str("s#");
+          migrated.push_str(text);
+        } else {
+          migrated.push_str(text);
+        };
+      }
+
+      TokenKind::RawIdent => migrated.push_str(text),
+
+      _ if attr_value.is_some_and(|(attr_idx, _)| attr_idx == idx) => {
+        migrated.push_str(&format!("\"{}\"", script_name(to)));
+      }
+
+      _ => migrated.push_str(text),
+    };
+  }
+
+  if let Some(guar) = psess.dcx().has_errors() {
+    return Err(guar);
+  };
+  return Ok(migrated);
+}
+
+/// Script of the keywords of `src`: `#![kilt_sozder = "..."]` or the default of the edition
+///
+/// This is the only place the script of a file is decided: the lexer needs it before the
+/// attribute is parsed, to lex `ras`/`jalgan` as literals, and the parser gets it from there
+pub fn file_keyword_script(src: &str, edition: Edition) -> KeywordScript {
+  return script_from_attr(find_keyword_script_attr(tokenize(src)), edition);
+}
+fn script_from_attr(attr_value: Option<(usize, &str)>, edition: Edition) -> KeywordScript {
+  return match attr_value.map(|(_, text)| text) {
+    Some("\"kirill\"") => KeywordScript::Cyrillic,
+    Some("\"latyn\"") => KeywordScript::Latin,
+
+    _ => edition.keyword_script(),
+  };
+}
+
+fn tokenize(src: &str) -> impl Iterator<Item = (TokenKind, &str)> {
+  let mut pos = 0;
+  return tilc_lexer::tokenize(src).map(move |token| {
+    let text = &src[pos..pos + token.len];
+    pos += token.len;
+    (token.kind, text)
+  });
+}
+
+/// Index and text of the value of the `kilt_sozder` attribute
+///
+/// Only the inner attributes of the file header are scanned, the scan stops at the first
+/// item, so the rest of the file is never lexed here
+fn find_keyword_script_attr<'a>(
+  tokens: impl Iterator<Item = (TokenKind, &'a str)>,
+) -> Option<(usize, &'a str)> {
+  let attr_name = sym::KeywordScript.as_str();
+  let mut significant = tokens.enumerate().filter(|(_, (kind, _))| {
+    !matches!(
+      kind,
+      TokenKind::Whitespace
+        | TokenKind::LineComment {
+          doc_style: None | Some(DocStyle::Inner),
+        }
+        | TokenKind::BlockComment {
+          doc_style: None | Some(DocStyle::Inner),
+          ..
+        }
+    )
+  });
+
+  let mut attr_value = None;
+  // #![kilt_sozder = "kirill"]
+  // ^^^
+  //   | an inner attribute, anything else starts the first item
+  while let (
+    Some((_, (TokenKind::Hashtag, _))),
+    Some((_, (TokenKind::Bang, _))),
+    Some((_, (TokenKind::OpenBracket, _))),
+  ) = (significant.next(), significant.next(), significant.next())
+  {
+    let mut depth = 0;
+    let mut args = Vec::new();
+    loop {
+      let Some(token) = significant.next() else {
+        return attr_value;
+      };
+      match token.1.0 {
+        TokenKind::OpenBracket => depth += 1,
+        TokenKind::CloseBracket if depth == 0 => break,
+        TokenKind::CloseBracket => depth -= 1,
+
+        _ => {}
+      };
+      args.push(token);
+    }
+
+    if let [
+      (_, (TokenKind::Ident, name)),
+      (_, (TokenKind::Eq, _)),
+      (idx, (TokenKind::Literal { .. }, value)),
+    ] = args[..]
+      && name == attr_name
+    {
+      attr_value = Some((idx, value));
+    };
+  }
+
+  return attr_value;
+}
+
+const fn script_name(script: KeywordScript) -> &'static str {
+  match script {
+    KeywordScript::Latin => "latyn",
+    KeywordScript::Cyrillic => "kirill",
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::tests_util::new_psess;
+
+  /// Migrated `src` and the number of reported errors
+  fn migrate(src: &str, to: KeywordScript) -> (Option<String>, usize) {
+    let psess = new_psess();
+
+    let migrated = migrate_keyword_script(&psess, src, BytePos::from_u32(0), to).ok();
+    return (migrated, psess.dcx().err_count());
+  }
+
+  #[test]
+  fn latin_to_cyrillic() {
+    let src = "fx basty() {\n  ainymaly егер = \"eger\";\n}\n";
+    let migrated = migrate(src, KeywordScript::Cyrillic).0.unwrap();
+    assert_eq!(
+      migrated,
+      "#![kilt_sozder = \"kirill\"]\nфх basty() {\n  айнымалы s#егер = \"eger\";\n}\n"
+    );
+
+    let back = migrate(&migrated, KeywordScript::Latin).0.unwrap();
+    assert_eq!(
+      back,
+      "#![kilt_sozder = \"latyn\"]\nfx basty() {\n  ainymaly s#егер = \"eger\";\n}\n"
+    );
+  }
+
+  #[test]
+  fn cyrillic_to_latin() {
+    let src = "#![kilt_sozder = \"kirill\"]\nфх basty() {\n  айнымалы eger = 1;\n}\n";
+    let migrated = migrate(src, KeywordScript::Latin).0.unwrap();
+    assert_eq!(
+      migrated,
+      "#![kilt_sozder = \"latyn\"]\nfx basty() {\n  ainymaly s#eger = 1;\n}\n"
+    );
+  }
+
+  #[test]
+  fn keywords_that_cannot_be_raw() {
+    let src = "#![kilt_sozder = \"kirill\"]\nфх basty(ozi: b32, super: b32) {}\n";
+    assert_eq!(migrate(src, KeywordScript::Latin), (None, 2));
+  }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use tilc_session::ParseSession;
+use tilc_span::{Span, Symbol};
+use unicode_security::{MixedScript, skeleton};
+
+/// Warns about identifiers that mix scripts (e.g. Cyrillic `а` inside of a Latin word)
+/// and about identifiers that can be confused with each other
+pub fn check_non_ascii_idents(psess: &ParseSession) {
+  let mut skeletons: HashMap<String, (Symbol, Span)> = HashMap::new();
+
+  psess.symbol_repo.for_each(|symbol, span| {
+    let name = symbol.as_str();
+    let name_skeleton = skeleton(name).collect::<String>();
+
+    if !name.is_ascii() && !name.is_single_script() {
+      psess
+        .dcx()
+        .struct_span_warn(
+          span,
+          format!(
+            "identifier `{}` contains characters from multiple scripts",
+            name
+          ),
+        )
+        .with_note(
+          "characters from different scripts may look the same, e.g. Cyrillic `а` and Latin `a`",
+        )
+        .emit();
+    };
+
+    match skeletons.get(&name_skeleton) {
+      Some(&(other, other_span)) if !name.is_ascii() || !other.as_str().is_ascii() => {
+        psess
+          .dcx()
+          .struct_span_warn(
+            span,
+            format!(
+              "identifier pair considered confusable between `{}` and `{}`",
+              other.as_str(),
+              name
+            ),
+          )
+          .with_label(other_span, "this is where the previous identifier occurred")
+          .emit();
+      }
+      Some(_) => {}
+
+      None => {
+        skeletons.insert(name_skeleton, (symbol, span));
+      }
+    };
+  });
+}
@@ -1,57 +1,144 @@
 use std::{path::Path, rc::Rc};
 
-use tilc_advanced_lexer::TokenReader;
+use tilc_advanced_lexer::{LosslessToken, TokenReader};
 use tilc_ast::TokenStream;
 use tilc_error::ErrorGuaranteed;
 use tilc_lexer::Lexer;
 use tilc_parser::Parser;
 use tilc_session::ParseSession;
-use tilc_span::{BytePos, SourceFile};
+use tilc_span::{BytePos, KeywordScript, SourceFile};
 
-use crate::TokenTreesReader;
+use crate::{TokenTreesReader, file_keyword_script};
 
 pub fn new_parser_from_file<'psess>(
   psess: &'psess ParseSession,
   path: &Path,
 ) -> Result<Parser<'psess>, ErrorGuaranteed> {
-  let source_file = psess.source_map().load_file(path).unwrap();
+  let source_file = match psess.source_map().load_file(path) {
+    Ok(source_file) => source_file,
+    Err(err) => {
+      let message = format!("couldn't read \"{}\": {err}", path.display());
+      return Err(psess.dcx().struct_err(message).emit());
+    }
+  };
   new_source_from_source_file(psess, source_file)
 }
-fn new_source_from_source_file<'psess>(
+pub(crate) fn new_source_from_source_file<'psess>(
   psess: &'psess ParseSession,
   source_file: Rc<SourceFile>,
 ) -> Result<Parser<'psess>, ErrorGuaranteed> {
-  let token_stream = source_file_to_stream(psess, source_file)?;
-  let parser = Parser::new(psess, token_stream);
-  Ok(parser)
-}
-
-fn source_file_to_stream<'psess>(
-  psess: &'psess ParseSession,
-  source_file: Rc<SourceFile>,
-) -> Result<TokenStream, ErrorGuaranteed> {
   let src = source_file.src();
+  let kw_script = file_keyword_script(src, psess.edition());
 
-  lex_token_stream(&psess, src, source_file.start())
+  let token_stream = lex_token_stream(psess, src, source_file.start(), kw_script)?;
+  let parser = Parser::new(psess, token_stream, kw_script);
+  Ok(parser)
 }
 
-fn lex_token_stream<'psess, 'src>(
+/// Lexes `src` with the keywords of `kw_script`, see `file_keyword_script`
+pub fn lex_token_stream<'psess, 'src>(
   psess: &'psess ParseSession,
   src: &'src str,
   start_pos: BytePos,
+  kw_script: KeywordScript,
 ) -> Result<TokenStream, ErrorGuaranteed> {
   let lexer = Lexer::new(src);
-  let token_reader = TokenReader::new(src, lexer, psess, start_pos, start_pos);
+  let mut token_reader = TokenReader::new(src, lexer, psess, kw_script, start_pos, start_pos);
+  let tokens = std::iter::from_fn(|| Some(token_reader.next_lossless_token()));
 
-  let (stream, err) = lex_all_token_trees(token_reader);
-  match err {
-    Ok(_) => Ok(stream),
-    Err(_) => panic!(),
-  }
+  // Unbalanced delimiters are already reported, the stream is still usable for parsing
+  let (stream, _) = lex_all_token_trees(psess, src, start_pos, tokens);
+  Ok(stream)
 }
-fn lex_all_token_trees(token_reader: TokenReader) -> (TokenStream, Result<(), ErrorGuaranteed>) {
-  let mut ttr = TokenTreesReader::new(token_reader);
+pub(crate) fn lex_all_token_trees(
+  psess: &ParseSession,
+  src: &str,
+  start_pos: BytePos,
+  tokens: impl Iterator<Item = LosslessToken>,
+) -> (TokenStream, Result<(), ErrorGuaranteed>) {
+  let mut ttr = TokenTreesReader::new(psess, src, start_pos, tokens);
 
   let (_, stream, err) = ttr.lex_token_trees(false);
   (stream, err)
 }
+
+#[cfg(test)]
+mod tests {
+  use tilc_ast::{Expr, ExprKind, Item, ItemKind, Lit, LitKind, PatternKind, StatementKind};
+
+  use crate::tests_util::{new_psess, parse_sandyq};
+
+  /// Items of `src` and the number of reported errors
+  fn parse_items(src: &str) -> (Vec<Item>, usize) {
+    let psess = new_psess();
+    let sandyq = parse_sandyq(&psess, src);
+    return (sandyq.items, psess.dcx().err_count());
+  }
+
+  #[test]
+  fn bool_literals() {
+    let is_bool = |expr: &Expr| {
+      matches!(
+        expr.kind,
+        ExprKind::Lit(Lit {
+          kind: LitKind::Bool,
+          ..
+        })
+      )
+    };
+    // Whether the scrutinee and the arm patterns of the `saikestir` in `f` are bool literals
+    let match_bools = |src: &str| {
+      let (items, errors) = parse_items(src);
+      assert_eq!(errors, 0);
+      let ItemKind::Fn(function) = &items[0].kind else {
+        unreachable!();
+      };
+      let StatementKind::Semi(expr) = &function.block.as_ref().unwrap().statements[0].kind else {
+        unreachable!();
+      };
+      let ExprKind::Match(scrutinee, arms) = &expr.kind else {
+        unreachable!();
+      };
+      let pats = arms
+        .iter()
+        .map(|arm| matches!(&arm.pat.kind, PatternKind::Expr(expr) if is_bool(expr)));
+      return (is_bool(scrutinee), pats.collect::<Vec<_>>());
+    };
+
+    assert_eq!(
+      match_bools("fx f() { saikestir ras { ras => 1, jalgan => 0 }; }"),
+      (true, vec![true, true])
+    );
+    // Cyrillic spellings are only literals in files with `#![kilt_sozder = "kirill"]`
+    assert_eq!(
+      match_bools("fx f() { saikestir рас { жалған => 1, _ => 0 }; }"),
+      (false, vec![false, false])
+    );
+    assert_eq!(
+      match_bools(
+        "#![kilt_sozder = \"kirill\"]\nфх f() { сәйкестір рас { жалған => 1, _ => 0 }; }"
+      ),
+      (true, vec![true, false])
+    );
+  }
+
+  #[test]
+  fn file_keyword_script() {
+    let (items, errors) = parse_items("#![kilt_sozder = \"kirill\"]\nфх f() { рас; }");
+    assert_eq!(errors, 0);
+    let ItemKind::Fn(function) = &items[0].kind else {
+      unreachable!();
+    };
+    assert!(matches!(
+      &function.block.as_ref().unwrap().statements[0].kind,
+      StatementKind::Semi(expr) if matches!(expr.kind, ExprKind::Lit(Lit { kind: LitKind::Bool, .. }))
+    ));
+
+    let header = "// Basty\n//! Kirill\n#![doc = \"[]\"]\n#![kilt_sozder = \"kirill\"]\nфх f() {}";
+    assert_eq!(parse_items(header).1, 0);
+    // Only the header decides the script, an inline korpe can't switch it
+    let (items, errors) = parse_items("korpe a { #![kilt_sozder = \"kirill\"] }\nfx f() { ras; }");
+    assert_eq!((items.len(), errors), (2, 1));
+    assert_eq!(parse_items("#![kilt_sozder = \"qazaq\"]\nfx f() {}").1, 1);
+  }
+}
@@ -0,0 +1,33 @@
+use tilc_ast::{NodeIdx, Sandyq};
+use tilc_parser::Parser;
+use tilc_session::ParseSession;
+use tilc_span::{BytePos, Pos, Span, with_session_globals};
+
+use crate::{file_keyword_script, lex_token_stream};
+
+/// Session over the source map of the session globals
+pub(crate) fn new_psess() -> ParseSession {
+  let source_map = with_session_globals(|session_globals| session_globals.source_map());
+  return ParseSession::new(source_map);
+}
+/// Lexes and parses `src` as a whole sandyq, the errors stay in `psess`
+///
+/// A sandyq that fails to parse is reported and comes back empty
+pub(crate) fn parse_sandyq(psess: &ParseSession, src: &str) -> Sandyq {
+  let kw_script = file_keyword_script(src, psess.edition());
+  let stream = lex_token_stream(psess, src, BytePos::from_u32(0), kw_script).unwrap();
+  return match Parser::new(psess, stream, kw_script).parse_sandyq() {
+    Ok(sandyq) => sandyq,
+    Err(diag) => {
+      diag.emit();
+      Sandyq {
+        idx: NodeIdx::DUMMY,
+
+        attrs: Vec::new(),
+        items: Vec::new(),
+
+        span: Span::EMPTY,
+      }
+    }
+  };
+}
@@ -1,19 +1,55 @@
-use tilc_advanced_lexer::TokenReader;
+use tilc_advanced_lexer::{LosslessToken, LosslessTokenKind};
 use tilc_ast::{Delim, DelimSpacing, DelimSpan, Spacing, Token, TokenKind, TokenStream, TokenTree};
-use tilc_error::ErrorGuaranteed;
+use tilc_error::{Diag, ErrorGuaranteed};
+use tilc_session::ParseSession;
+use tilc_span::{BytePos, Pos, Span, SpanCtxt};
 
-pub(crate) struct TokenTreesReader<'psess, 'lex> {
-  token_reader: TokenReader<'psess, 'lex>,
+/// Builds token trees out of lossless tokens, from a `TokenReader` or already lexed ones
+pub(crate) struct TokenTreesReader<'psess, 'src, I> {
+  psess: &'psess ParseSession,
+  src: &'src str,
+  start_pos: BytePos,
+  tokens: I,
   token: Token,
+
+  /// Currently open delimiters with their spans
+  open_delims: Vec<(Delim, Span)>,
+  /// Openers that reached the end of the file
+  unclosed_delims: Vec<Span>,
+  /// Spans of every matched pair of braces, used for the indentation heuristics
+  matching_block_spans: Vec<(Span, Span)>,
+  /// Closing delimiter that was already reported as mismatched
+  last_unclosed_found_span: Option<Span>,
+
+  err: Option<ErrorGuaranteed>,
 }
-impl<'psess, 'lex> TokenTreesReader<'psess, 'lex> {
-  pub(crate) fn new(token_reader: TokenReader<'psess, 'lex>) -> Self {
+impl<'psess, 'src, I: Iterator<Item = LosslessToken>> TokenTreesReader<'psess, 'src, I> {
+  pub(crate) fn new(
+    psess: &'psess ParseSession,
+    src: &'src str,
+    start_pos: BytePos,
+    tokens: I,
+  ) -> Self {
     Self {
-      token_reader,
+      psess,
+      src,
+      start_pos,
+      tokens,
       token: Token::DUMMY,
+
+      open_delims: Vec::new(),
+      unclosed_delims: Vec::new(),
+      matching_block_spans: Vec::new(),
+      last_unclosed_found_span: None,
+
+      err: None,
     }
   }
 
+  /// Reads the token trees until the end of the file (or the closing delimiter if `from_delim`)
+  ///
+  /// Unbalanced delimiters are reported, but the stream is always produced so parsing can
+  /// continue, the returned error only tells that something was reported
   pub(crate) fn lex_token_trees(
     &mut self,
     from_delim: bool,
@@ -23,25 +59,21 @@ impl<'psess, 'lex> TokenTreesReader<'psess, 'lex> {
     let mut buffer = Vec::new();
     loop {
       match self.token.kind {
-        // TODO: Check for from_delim, return err in case from_delim is true
-        TokenKind::Eof => return (spacing, TokenStream::new(buffer), Ok(())),
-
-        TokenKind::OpenDelim(delim) => {
-          match self.lex_token_tree_in_open_delim(delim) {
-            Ok(token_tree) => buffer.push(token_tree),
-            Err(_) => todo!(),
+        TokenKind::Eof => {
+          if !from_delim {
+            self.report_unclosed_delims();
           };
+          return (spacing, TokenStream::new(buffer), self.result());
         }
-        TokenKind::CloseDelim(_delim) => {
-          // TODO: Implement Diagnostics
-          // Note: Do not put close delim token inside buffer
-          //       Close dedlims must be handled in `Self::lex_token_tree_in_open_delim`'
-          // Maybe there is a better way but idk
-          return (
-            spacing,
-            TokenStream::new(buffer),
-            if from_delim { Ok(()) } else { todo!() },
-          );
+
+        TokenKind::OpenDelim(delim) => buffer.push(self.lex_token_tree_in_open_delim(delim)),
+        // Close delims are handled in `Self::lex_token_tree_in_open_delim`
+        TokenKind::CloseDelim(_) if from_delim => {
+          return (spacing, TokenStream::new(buffer), self.result());
+        }
+        TokenKind::CloseDelim(delim) => {
+          self.report_unexpected_close_delim(delim);
+          self.step(false);
         }
 
         _ => {
@@ -51,15 +83,10 @@ impl<'psess, 'lex> TokenTreesReader<'psess, 'lex> {
       }
     }
   }
-  fn lex_token_tree_in_open_delim(
-    &mut self,
-    opening_delim: Delim,
-  ) -> Result<TokenTree, ErrorGuaranteed> {
+  fn lex_token_tree_in_open_delim(&mut self, opening_delim: Delim) -> TokenTree {
     let delim_start_span = self.token.span;
-    let (spacing, token_stream, err) = self.lex_token_trees(true);
-    if let Err(_) = err {
-      todo!("Error handling is not implemented yet");
-    };
+    self.open_delims.push((opening_delim, delim_start_span));
+    let (spacing, token_stream, _) = self.lex_token_trees(true);
 
     let delim_span = DelimSpan {
       start: delim_start_span,
@@ -68,13 +95,39 @@ impl<'psess, 'lex> TokenTreesReader<'psess, 'lex> {
     let close_spacing = match self.token.kind {
       // Case when closing and opening delims match
       // If delims match just step one token forward
-      TokenKind::CloseDelim(delim) if delim == opening_delim => self.step(true).0,
+      TokenKind::CloseDelim(delim) if delim == opening_delim => {
+        self.open_delims.pop();
+        if delim == Delim::Brace {
+          self
+            .matching_block_spans
+            .push((delim_start_span, self.token.span));
+        };
+        self.step(true).0
+      }
 
       // Case when delims do not match
       TokenKind::CloseDelim(delim) => {
-        todo!();
+        let found_span = self.token.span;
+        if self.last_unclosed_found_span != Some(found_span) {
+          self.last_unclosed_found_span = Some(found_span);
+          self.open_delims.pop();
+          self.report_mismatched_delim(delim, found_span, delim_start_span);
+        } else {
+          self.open_delims.pop();
+        };
+
+        // If the closing delimiter closes an outer delimiter, leave it for that one
+        if self.open_delims.iter().any(|&(open, _)| open == delim) {
+          Spacing::Whitespaced
+        } else {
+          self.step(true).0
+        }
+      }
+      TokenKind::Eof => {
+        self.open_delims.pop();
+        self.unclosed_delims.push(delim_start_span);
+        Spacing::Whitespaced
       }
-      TokenKind::Eof => Spacing::Whitespaced,
 
       _ => unreachable!("It was supposed to be unreachble part of the code, what did you do?"),
     };
@@ -83,16 +136,147 @@ impl<'psess, 'lex> TokenTreesReader<'psess, 'lex> {
       start: spacing,
       end: close_spacing,
     };
-    Ok(TokenTree::Delimited(
-      delim_span,
-      delim_spacing,
-      opening_delim,
-      token_stream,
-    ))
+    TokenTree::Delimited(delim_span, delim_spacing, opening_delim, token_stream)
+  }
+
+  fn report_mismatched_delim(&mut self, found: Delim, found_span: Span, unclosed_span: Span) {
+    let mut diag = self
+      .psess
+      .dcx()
+      .struct_span_err(
+        found_span,
+        format!("mismatched closing delimiter: `{}`", found.close_str()),
+      )
+      .with_label(found_span, "mismatched closing delimiter")
+      .with_label(unclosed_span, "unclosed delimiter");
+
+    // An outer delimiter on the same indentation level was probably meant to be closed
+    let candidate = self
+      .open_delims
+      .iter()
+      .rev()
+      .find(|&&(open, open_span)| open == found && self.same_indentation(open_span, found_span));
+    if let Some(&(_, candidate_span)) = candidate {
+      diag = diag.with_label(candidate_span, "closing delimiter possibly meant for this");
+    };
+
+    self.err = Some(diag.emit());
+  }
+  fn report_unexpected_close_delim(&mut self, delim: Delim) {
+    let span = self.token.span;
+    let diag = self
+      .psess
+      .dcx()
+      .struct_span_err(
+        span,
+        format!("unexpected closing delimiter: `{}`", delim.close_str()),
+      )
+      .with_label(span, "unexpected closing delimiter");
+
+    let diag = self.with_indentation_hints(diag, Span::EMPTY);
+    self.err = Some(diag.emit());
+  }
+  fn report_unclosed_delims(&mut self) {
+    let Some(&first_unclosed) = self.unclosed_delims.first() else {
+      return;
+    };
+
+    let mut diag = self
+      .psess
+      .dcx()
+      .struct_span_err(self.token.span, "this file contains an unclosed delimiter");
+    for &unclosed_span in &self.unclosed_delims {
+      diag = diag.with_label(unclosed_span, "unclosed delimiter");
+    }
+
+    let diag = self.with_indentation_hints(diag, first_unclosed);
+    self.err = Some(diag.emit());
+    self.unclosed_delims.clear();
+  }
+  /// Points at the first matched pair (after `after`) whose closing delimiter isn't on the
+  /// indentation level of the line with the opening one, which is likely where a delimiter
+  /// is missing
+  fn with_indentation_hints<'a>(&self, diag: Diag<'a>, after: Span) -> Diag<'a> {
+    let mismatched = self
+      .matching_block_spans
+      .iter()
+      .filter(|(open, _)| open.lo() >= after.lo())
+      .min_by_key(|(open, _)| open.lo())
+      .filter(|&&(open, close)| !self.same_indentation(open, close));
+
+    let Some(&(open, close)) = mismatched else {
+      return diag;
+    };
+    diag
+      .with_label(open, "this delimiter might not be properly closed...")
+      .with_label(
+        close,
+        "...as it matches this but it has different indentation",
+      )
+  }
+
+  /// Checks that `close` is the first thing on its line and is indented like the line of `open`
+  fn same_indentation(&self, open: Span, close: Span) -> bool {
+    let (open_line, _) = self.line_of(open.lo());
+    let (close_line, close_column) = self.line_of(close.lo());
+    let indent = |line: &str| line.len() - line.trim_start().len();
+
+    indent(close_line) == close_column && indent(open_line) == indent(close_line)
+  }
+  /// Line containing `pos` and the column (in bytes) of `pos` in it
+  fn line_of(&self, pos: BytePos) -> (&str, usize) {
+    let src = self.src;
+    let offset = (pos - self.start_pos).to_usize().min(src.len());
+    let line_start = src[..offset].rfind('\n').map_or(0, |idx| idx + 1);
+    let line_end = src[offset..]
+      .find('\n')
+      .map_or(src.len(), |idx| offset + idx);
+
+    (&src[line_start..line_end], offset - line_start)
+  }
+
+  fn result(&self) -> Result<(), ErrorGuaranteed> {
+    match self.err {
+      Some(guar) => Err(guar),
+      None => Ok(()),
+    }
+  }
+
+  /// Next token that isn't trivia, and whether trivia came before it
+  fn next_token(&mut self) -> (Token, bool) {
+    let mut with_whitespace = false;
+
+    loop {
+      let Some(token) = self.tokens.next() else {
+        let end = self.start_pos + BytePos::from_usize(self.src.len());
+        let span = Span::new(end, end, SpanCtxt::ROOT, None);
+        return (
+          Token {
+            kind: TokenKind::Eof,
+            span,
+          },
+          with_whitespace,
+        );
+      };
+      match token.kind {
+        LosslessTokenKind::Whitespace => with_whitespace = true,
+        LosslessTokenKind::Comment | LosslessTokenKind::Unknown => {}
+
+        LosslessTokenKind::Token(kind) => {
+          return (
+            Token {
+              kind,
+              span: token.span,
+            },
+            with_whitespace,
+          );
+        }
+      };
+    }
   }
   fn step(&mut self, glue: bool) -> (Spacing, Token) {
     let (spacing, next_token) = loop {
-      let (next_token, is_whitespaced) = self.token_reader.next_token();
+      let (next_token, is_whitespaced) = self.next_token();
 
       if is_whitespaced {
         break (Spacing::Whitespaced, next_token);
@@ -116,3 +300,65 @@ impl<'psess, 'lex> TokenTreesReader<'psess, 'lex> {
     (spacing, current_token)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use tilc_span::KeywordScript;
+
+  use super::*;
+  use crate::{lex_token_stream, tests_util::new_psess};
+
+  /// Messages and labels of the reported diagnostics
+  fn lex(src: &str) -> Vec<(String, Vec<String>)> {
+    let psess = new_psess();
+    let _ = lex_token_stream(&psess, src, BytePos::from_u32(0), KeywordScript::Latin);
+
+    psess.dcx().with_emitted(|diags| {
+      diags
+        .iter()
+        .map(|diag| {
+          let labels = diag.labels.iter().map(|(_, label)| label.to_string());
+          (diag.message.to_string(), labels.collect())
+        })
+        .collect()
+    })
+  }
+
+  #[test]
+  fn balanced() {
+    assert!(lex("fx basty() {\n  eger x {\n    f(a[1]);\n  }\n}\n").is_empty());
+  }
+
+  #[test]
+  fn mismatched_closing_delimiter() {
+    let diags = lex("fx basty() {\n  ainymaly a = (1];\n}\n");
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].0, "mismatched closing delimiter: `]`");
+    assert_eq!(
+      diags[0].1,
+      ["mismatched closing delimiter", "unclosed delimiter"]
+    );
+  }
+
+  #[test]
+  fn unclosed_delimiter() {
+    let diags = lex("fx basty() {\n  eger x {\n    ainymaly a = 1;\n}\n");
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].0, "this file contains an unclosed delimiter");
+    assert_eq!(
+      diags[0].1,
+      [
+        "unclosed delimiter",
+        "this delimiter might not be properly closed...",
+        "...as it matches this but it has different indentation",
+      ]
+    );
+  }
+
+  #[test]
+  fn unexpected_closing_delimiter() {
+    let diags = lex("fx basty() {\n}\n}\n");
+    assert_eq!(diags.len(), 1);
+    assert_eq!(diags[0].0, "unexpected closing delimiter: `}`");
+  }
+}
@@ -0,0 +1,208 @@
+use tilc_ast::{
+  AttributeStyle, Delim, EnumVariant, FieldDef, ItemKind, NodeIdx, TokenKind, VariantKind, VisKind,
+};
+use tilc_error::PResult;
+use tilc_span::kw;
+
+use crate::{ItemInfo, Parser};
+
+impl<'a> Parser<'a> {
+  /// `qurylym A<T> { x: T }`, `qurylym A(b32);` or `qurylym A;` after the `qurylym`
+  pub(crate) fn parse_struct_item(&mut self) -> PResult<'a, ItemInfo> {
+    debug_assert!(self.token_is_kw(self.prev_token, kw::Struct));
+    let ident = self.parse_ident()?;
+    let generics = self.parse_generics()?;
+
+    let variant_kind = if self.eat(TokenKind::Semicolon) {
+      VariantKind::Unit
+    } else if self.check(TokenKind::OpenDelim(Delim::Brace)) {
+      VariantKind::Struct(self.parse_named_fields()?)
+    } else if self.check(TokenKind::OpenDelim(Delim::Paren)) {
+      let fields = self.parse_tuple_fields()?;
+      self.expect(TokenKind::Semicolon)?;
+      VariantKind::Tuple(fields)
+    } else {
+      return Err(self.expected_err("`{`, `(` or `;`".to_string()));
+    };
+
+    return Ok((ident, ItemKind::Struct(ident, generics, variant_kind)));
+  }
+  /// `birlestik A { x: b32, y: q32 }` after the `birlestik`
+  pub(crate) fn parse_union_item(&mut self) -> PResult<'a, ItemInfo> {
+    debug_assert!(self.token_is_kw(self.prev_token, kw::Union));
+    let ident = self.parse_ident()?;
+    let generics = self.parse_generics()?;
+
+    if !self.check(TokenKind::OpenDelim(Delim::Brace)) {
+      return Err(
+        self
+          .expected_err("`{`".to_string())
+          .with_note("unions can only have named fields"),
+      );
+    };
+    let fields = self.parse_named_fields()?;
+
+    return Ok((
+      ident,
+      ItemKind::Union(ident, generics, VariantKind::Struct(fields)),
+    ));
+  }
+  /// `tizbe A { B, C(b32), D { x: b32 }, E = 4 }` after the `tizbe`
+  pub(crate) fn parse_enum_item(&mut self) -> PResult<'a, ItemInfo> {
+    debug_assert!(self.token_is_kw(self.prev_token, kw::Enum));
+    let ident = self.parse_ident()?;
+    let generics = self.parse_generics()?;
+
+    self.expect(TokenKind::OpenDelim(Delim::Brace))?;
+    let mut variants = Vec::new();
+    while !self.eat(TokenKind::CloseDelim(Delim::Brace)) {
+      variants.push(self.parse_enum_variant()?);
+
+      if !self.eat(TokenKind::Comma) {
+        self.expect(TokenKind::CloseDelim(Delim::Brace))?;
+        break;
+      };
+    }
+
+    return Ok((ident, ItemKind::Enum(ident, generics, variants)));
+  }
+  fn parse_enum_variant(&mut self) -> PResult<'a, EnumVariant> {
+    let attrs = self.parse_attributes(AttributeStyle::Outer)?;
+    let lo = self.token.span;
+
+    let vis = self.parse_vis(false)?;
+    if !matches!(vis.kind, VisKind::Private) {
+      self
+        .dcx()
+        .struct_span_err(
+          vis.span,
+          "visibility qualifiers are not permitted on enum variants",
+        )
+        .with_label(vis.span, "enum variants inherit the visibility of the enum")
+        .emit();
+    };
+
+    let ident = self.parse_ident()?;
+    let kind = if self.check(TokenKind::OpenDelim(Delim::Brace)) {
+      VariantKind::Struct(self.parse_named_fields()?)
+    } else if self.check(TokenKind::OpenDelim(Delim::Paren)) {
+      VariantKind::Tuple(self.parse_tuple_fields()?)
+    } else {
+      VariantKind::Unit
+    };
+    let disr_expr = if self.eat(TokenKind::Eq) {
+      Some(self.parse_expr()?)
+    } else {
+      None
+    };
+
+    return Ok(EnumVariant {
+      idx: NodeIdx::DUMMY,
+
+      attrs,
+      ident,
+      kind,
+      disr_expr,
+
+      span: lo.to(self.prev_token.span),
+    });
+  }
+
+  /// `{ barsha x: b32, #[attr] y: q32, }`
+  fn parse_named_fields(&mut self) -> PResult<'a, Vec<FieldDef>> {
+    self.expect(TokenKind::OpenDelim(Delim::Brace))?;
+
+    let mut fields = Vec::new();
+    while !self.eat(TokenKind::CloseDelim(Delim::Brace)) {
+      fields.push(self.parse_field_def(true)?);
+
+      if !self.eat(TokenKind::Comma) {
+        self.expect(TokenKind::CloseDelim(Delim::Brace))?;
+        break;
+      };
+    }
+
+    return Ok(fields);
+  }
+  /// `(barsha b32, #[attr] q32,)`
+  fn parse_tuple_fields(&mut self) -> PResult<'a, Vec<FieldDef>> {
+    self.expect(TokenKind::OpenDelim(Delim::Paren))?;
+
+    let mut fields = Vec::new();
+    while !self.eat(TokenKind::CloseDelim(Delim::Paren)) {
+      fields.push(self.parse_field_def(false)?);
+
+      if !self.eat(TokenKind::Comma) {
+        self.expect(TokenKind::CloseDelim(Delim::Paren))?;
+        break;
+      };
+    }
+
+    return Ok(fields);
+  }
+  fn parse_field_def(&mut self, is_named: bool) -> PResult<'a, FieldDef> {
+    let attrs = self.parse_attributes(AttributeStyle::Outer)?;
+    let lo = self.token.span;
+    let vis = self.parse_vis(!is_named)?;
+
+    let ident = if is_named {
+      let ident = self.parse_ident()?;
+      self.expect(TokenKind::Colon)?;
+      Some(ident)
+    } else {
+      None
+    };
+    let ty = self.parse_ty()?;
+
+    return Ok(FieldDef {
+      idx: NodeIdx::DUMMY,
+
+      attrs,
+      vis,
+      ident,
+      ty,
+
+      span: lo.to(self.prev_token.span),
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use tilc_ast::{ItemKind, VariantKind, VisKind};
+
+  use crate::tests_util::{parse_items, render};
+
+  #[test]
+  fn adts() {
+    let (items, errors) = parse_items(
+      "qurylym A<T> { barsha x: T, #[doc = \"y\"] y: b32, }
+      qurylym B(barsha b8, q32);
+      qurylym C;
+      birlestik D { x: b32 }
+      tizbe E { F, G(b32,), H { x: b32 }, I = 1 + 2 }",
+    );
+    assert_eq!(errors, 0);
+    let ItemKind::Struct(_, _, VariantKind::Struct(fields)) = &items[0].kind else {
+      unreachable!();
+    };
+    assert!(matches!(fields[0].vis.kind, VisKind::Public));
+    assert_eq!(fields[1].attrs.len(), 1);
+    assert!(
+      matches!(&items[1].kind, ItemKind::Struct(_, _, VariantKind::Tuple(fields)) if fields.len() == 2)
+    );
+    assert!(matches!(
+      &items[2].kind,
+      ItemKind::Struct(_, _, VariantKind::Unit)
+    ));
+    assert!(matches!(&items[3].kind, ItemKind::Union(..)));
+    let ItemKind::Enum(_, _, variants) = &items[4].kind else {
+      unreachable!();
+    };
+    assert!(matches!(variants[1].kind, VariantKind::Tuple(_)));
+    assert!(matches!(variants[2].kind, VariantKind::Struct(_)));
+    assert_eq!(render(variants[3].disr_expr.as_ref().unwrap()), "(1 + 2)");
+
+    assert_eq!(parse_items("tizbe A { barsha B }").1, 1);
+  }
+}
@@ -1,5 +1,9 @@
-use tilc_ast::{AttrArgs, Attribute, AttributeStyle, Delim, Path, TokenKind};
+use tilc_ast::{
+  AttrArgs, Attribute, AttributeStyle, Delim, ExprKind, Lit, LitKind, NodeIdx, Path, PathSegment,
+  TokenKind,
+};
 use tilc_error::PResult;
+use tilc_span::{Ident, sym};
 
 use crate::Parser;
 
@@ -21,6 +25,8 @@ impl<'a> Parser<'a> {
         && self.look_ahead_and(1, |t| t.kind == TokenKind::Bang)
       {
         Some(self.parse_attr()?)
+      } else if let TokenKind::DocComment(_, AttributeStyle::Inner, _) = self.token.kind {
+        Some(self.parse_doc_comment())
       } else {
         None
       };
@@ -40,6 +46,8 @@ impl<'a> Parser<'a> {
     loop {
       let attr = if self.check(TokenKind::Hashtag) {
         Some(self.parse_attr()?)
+      } else if let TokenKind::DocComment(..) = self.token.kind {
+        Some(self.parse_doc_comment())
       } else {
         None
       };
@@ -80,6 +88,43 @@ impl<'a> Parser<'a> {
       span: attr_span,
     });
   }
+  /// Doc comments are sugar for `#[doc = "..."]` (or `#![doc = "..."]`)
+  fn parse_doc_comment(&mut self) -> Attribute {
+    let TokenKind::DocComment(_, style, symbol) = self.token.kind else {
+      unreachable!();
+    };
+    let span = self.token.span;
+    self.step();
+
+    let path = Path {
+      segments: vec![PathSegment {
+        ident: Ident {
+          name: sym::Doc,
+          span,
+        },
+        idx: NodeIdx::DUMMY,
+      }],
+      span,
+    };
+    let lit = Lit {
+      kind: LitKind::Str,
+      symbol,
+      suffix: None,
+    };
+
+    return Attribute {
+      idx: self.psess.make_attr_idx(),
+
+      path,
+      args: AttrArgs::Eq {
+        eq_span: span,
+        expr: self.make_expr(Vec::new(), ExprKind::Lit(lit), span),
+      },
+
+      style,
+      span,
+    };
+  }
   fn parse_attr_item(&mut self) -> PResult<'a, (Path, AttrArgs)> {
     let path = self.parse_path()?;
     let args = self.parse_attr_args()?;
@@ -99,4 +144,49 @@ impl<'a> Parser<'a> {
       return Ok(AttrArgs::Empty);
     };
   }
+
+  /// Checks `#![kilt_sozder = "latyn"]` or `#![kilt_sozder = "kirill"]`
+  ///
+  /// The script itself is taken from the file header before lexing (see `Parser::kw_script`),
+  /// so the attribute is only allowed there: an inline korpe can't switch the script of the
+  /// tokens that are already lexed
+  pub(crate) fn check_keyword_script_attrs(&self, attrs: &[Attribute], is_file: bool) {
+    for attr in attrs {
+      if !matches!(attr.style, AttributeStyle::Inner) || !attr.path.is_ident(sym::KeywordScript) {
+        continue;
+      };
+
+      if !is_file {
+        self
+          .dcx()
+          .struct_span_err(
+            attr.span,
+            "`kilt_sozder` can only be set at the top of a file",
+          )
+          .with_note("the keywords of a whole file are in one script")
+          .emit();
+        continue;
+      };
+
+      let is_script = match &attr.args {
+        AttrArgs::Eq { expr, .. } => matches!(
+          expr.kind,
+          ExprKind::Lit(Lit {
+            kind: LitKind::Str,
+            symbol: sym::Latin | sym::Cyrillic,
+            ..
+          })
+        ),
+
+        _ => false,
+      };
+      if !is_script {
+        self
+          .dcx()
+          .struct_span_err(attr.span, "malformed `kilt_sozder` attribute")
+          .with_help("use `#![kilt_sozder = \"latyn\"]` or `#![kilt_sozder = \"kirill\"]`")
+          .emit();
+      };
+    }
+  }
 }
@@ -1,31 +1,606 @@
-use tilc_ast::{Attribute, AttributeStyle, Expr, ExprKind, NodeIdx, TokenKind};
+use std::ops::Bound;
+
+use tilc_ast::{
+  Arm, AssignOpKind, Attribute, AttributeStyle, BinOp, BinOpKind, CaptureBy, Closure, Delim, Expr,
+  ExprKind, FnDecl, FnReturnType, Label, Lit, LitKind, MethodCall, Mutability, NodeIdx, Param,
+  PathSegment, RangeLimits, Safety, Spacing, TokenKind, Ty, TyKind, UnOp,
+};
 use tilc_error::PResult;
-use tilc_span::{Span, kw};
+use tilc_span::{BytePos, Ident, Pos, Span, Spanned, Symbol, kw};
 
 use crate::Parser;
 
+/// Binding power of the expressions, from the loosest to the tightest
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq, PartialOrd)]
+pub(crate) enum ExprPrecedence {
+  /// `=`, `+=`
+  Assign,
+  /// `..`, `..=`
+  Range,
+  /// `||`
+  Or,
+  /// `&&`
+  And,
+  /// `==`, `<`, ...
+  Compare,
+  /// `|`
+  BitOr,
+  /// `^`
+  BitXor,
+  /// `&`
+  BitAnd,
+  /// `<<`, `>>`
+  Shift,
+  /// `+`, `-`
+  Sum,
+  /// `*`, `/`, `%`
+  Product,
+  /// `sekildi`
+  Cast,
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+enum Fixity {
+  /// `a - b - c` is `(a - b) - c`
+  Left,
+  /// `a = b = c` is `a = (b = c)`
+  Right,
+  /// `a < b < c` is an error
+  None,
+}
+
+/// Operator that takes a left hand side
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+enum AssocOp {
+  Binary(BinOpKind),
+  Assign,
+  AssignOp(AssignOpKind),
+  Cast,
+  Range(RangeLimits),
+}
+impl AssocOp {
+  fn precedence(self) -> ExprPrecedence {
+    match self {
+      Self::Assign | Self::AssignOp(_) => ExprPrecedence::Assign,
+      Self::Cast => ExprPrecedence::Cast,
+      Self::Range(_) => ExprPrecedence::Range,
+      Self::Binary(op) => match op {
+        BinOpKind::Or => ExprPrecedence::Or,
+        BinOpKind::And => ExprPrecedence::And,
+        BinOpKind::Eq
+        | BinOpKind::Lt
+        | BinOpKind::Le
+        | BinOpKind::Ne
+        | BinOpKind::Ge
+        | BinOpKind::Gt => ExprPrecedence::Compare,
+        BinOpKind::BitOr => ExprPrecedence::BitOr,
+        BinOpKind::BitXor => ExprPrecedence::BitXor,
+        BinOpKind::BitAnd => ExprPrecedence::BitAnd,
+        BinOpKind::Shl | BinOpKind::Shr => ExprPrecedence::Shift,
+        BinOpKind::Add | BinOpKind::Sub => ExprPrecedence::Sum,
+        BinOpKind::Mul | BinOpKind::Div | BinOpKind::Rem => ExprPrecedence::Product,
+      },
+    }
+  }
+  fn fixity(self) -> Fixity {
+    match self {
+      Self::Assign | Self::AssignOp(_) => Fixity::Right,
+      Self::Binary(op) if op.is_comparison() => Fixity::None,
+      Self::Range(_) => Fixity::None,
+      Self::Binary(_) | Self::Cast => Fixity::Left,
+    }
+  }
+}
+
 impl<'a> Parser<'a> {
   pub(crate) fn parse_expr(&mut self) -> PResult<'a, Box<Expr>> {
     let outer_attrs = self.parse_attributes(AttributeStyle::Outer)?;
+    return self.parse_expr_assoc_with(Bound::Unbounded, outer_attrs);
+  }
+
+  /// Expression of an expression statement
+  ///
+  /// Block-like expressions end the statement right after their block, so
+  /// `eger a {} -1` is two statements, but `saikestir a {}.b() + 1` is one
+  pub(crate) fn parse_expr_stmt(&mut self) -> PResult<'a, Box<Expr>> {
+    if !self.check_block_like_start() {
+      return self.parse_expr();
+    };
+
+    let expr = self.parse_expr_bottom(Vec::new())?;
+    if matches!(self.token.kind, TokenKind::Dot | TokenKind::Question) {
+      let expr = self.parse_expr_postfix(expr)?;
+      return self.parse_expr_assoc_rest_with(Bound::Unbounded, expr);
+    };
+
+    return Ok(expr);
+  }
+  fn check_block_like_start(&self) -> bool {
+    return [kw::If, kw::Match, kw::For, kw::While, kw::Loop]
+      .into_iter()
+      .any(|kw| self.check_kw(kw))
+      || self.check_label()
+      || self.check(TokenKind::OpenDelim(Delim::Brace))
+      || self.check_unsafe_block();
+  }
+  fn check_unsafe_block(&self) -> bool {
+    return self.check_kw(kw::Unsafe)
+      && self.look_ahead(1).kind == TokenKind::OpenDelim(Delim::Brace);
+  }
+
+  /// Parses an expression whose operators bind tighter than `min_prec`
+  fn parse_expr_assoc_with(
+    &mut self,
+    min_prec: Bound<ExprPrecedence>,
+    attrs: Vec<Attribute>,
+  ) -> PResult<'a, Box<Expr>> {
+    let lhs = if let Some((AssocOp::Range(limits), _)) = self.check_assoc_op() {
+      // `..a`
+      self.step();
+      self.parse_expr_range_rest(None, limits, self.prev_token.span)?
+    } else {
+      self.parse_expr_prefix(attrs)?
+    };
+    return self.parse_expr_assoc_rest_with(min_prec, lhs);
+  }
+  fn parse_expr_assoc_rest_with(
+    &mut self,
+    min_prec: Bound<ExprPrecedence>,
+    mut lhs: Box<Expr>,
+  ) -> PResult<'a, Box<Expr>> {
+    while let Some((op, len)) = self.check_assoc_op() {
+      let prec = op.precedence();
+      let is_too_loose = match min_prec {
+        Bound::Included(min_prec) => prec < min_prec,
+        Bound::Excluded(min_prec) => prec <= min_prec,
+        Bound::Unbounded => false,
+      };
+      if is_too_loose {
+        break;
+      };
+
+      let op_lo = self.token.span;
+      for _ in 0..len {
+        self.step();
+      }
+      let op_span = op_lo.to(self.prev_token.span);
+
+      if op.fixity() == Fixity::None {
+        self.check_comparison_chain(&lhs, op, op_span);
+      };
+      if let AssocOp::Range(limits) = op {
+        if let ExprKind::Range(..) = lhs.kind {
+          self
+            .dcx()
+            .struct_span_err(op_span, "range operators cannot be chained")
+            .with_label(lhs.span, "this is already a range")
+            .emit();
+        };
+
+        lhs = self.parse_expr_range_rest(Some(lhs), limits, op_span)?;
+        continue;
+      };
+
+      if op == AssocOp::Cast {
+        let ty = self.parse_ty()?;
+        let span = lhs.span.to(self.prev_token.span);
+        lhs = self.make_expr(Vec::new(), ExprKind::Cast(lhs, ty), span);
+        continue;
+      };
+
+      let rhs_prec = match op.fixity() {
+        Fixity::Right => Bound::Included(prec),
+        Fixity::Left | Fixity::None => Bound::Excluded(prec),
+      };
+      let rhs = self.parse_expr_assoc_with(rhs_prec, Vec::new())?;
+      let span = lhs.span.to(rhs.span);
+
+      let kind = match op {
+        AssocOp::Binary(node) => ExprKind::Binary(
+          Spanned {
+            node,
+            span: op_span,
+          },
+          lhs,
+          rhs,
+        ),
+        AssocOp::Assign => ExprKind::Assign(lhs, op_span, rhs),
+        AssocOp::AssignOp(node) => ExprKind::AssignOp(
+          Spanned {
+            node,
+            span: op_span,
+          },
+          lhs,
+          rhs,
+        ),
+        AssocOp::Cast | AssocOp::Range(_) => unreachable!(),
+      };
+      lhs = self.make_expr(Vec::new(), kind, span);
+    }
+
+    return Ok(lhs);
+  }
+  /// Operator at the token and the number of tokens it consists of
+  ///
+  /// `<<` and `>>` are not glued by the lexer because of `Vec<Vec<b8>>`, so they are
+  /// recognized here from two sticked tokens
+  fn check_assoc_op(&self) -> Option<(AssocOp, usize)> {
+    let is_sticked = matches!(self.token_spacing, Spacing::Sticked);
+    let next = self.look_ahead(1).kind;
+
+    let op = match self.token.kind {
+      TokenKind::Lt if is_sticked && next == TokenKind::Lt => {
+        return Some((AssocOp::Binary(BinOpKind::Shl), 2));
+      }
+      TokenKind::Lt if is_sticked && next == TokenKind::Le => {
+        return Some((AssocOp::AssignOp(AssignOpKind::ShlAssign), 2));
+      }
+      TokenKind::Gt if is_sticked && next == TokenKind::Gt => {
+        return Some((AssocOp::Binary(BinOpKind::Shr), 2));
+      }
+      TokenKind::Gt if is_sticked && next == TokenKind::Ge => {
+        return Some((AssocOp::AssignOp(AssignOpKind::ShrAssign), 2));
+      }
+
+      TokenKind::BinOp(op) => AssocOp::Binary(match op {
+        BinOp::Plus => BinOpKind::Add,
+        BinOp::Minus => BinOpKind::Sub,
+        BinOp::Star => BinOpKind::Mul,
+        BinOp::Slash => BinOpKind::Div,
+        BinOp::Percent => BinOpKind::Rem,
+        BinOp::Caret => BinOpKind::BitXor,
+        BinOp::And => BinOpKind::BitAnd,
+        BinOp::Or => BinOpKind::BitOr,
+      }),
+      TokenKind::BinOpEq(op) => AssocOp::AssignOp(match op {
+        BinOp::Plus => AssignOpKind::AddAssign,
+        BinOp::Minus => AssignOpKind::SubAssign,
+        BinOp::Star => AssignOpKind::MulAssign,
+        BinOp::Slash => AssignOpKind::DivAssign,
+        BinOp::Percent => AssignOpKind::RemAssign,
+        BinOp::Caret => AssignOpKind::BitXorAssign,
+        BinOp::And => AssignOpKind::BitAndAssign,
+        BinOp::Or => AssignOpKind::BitOrAssign,
+      }),
+      TokenKind::AndAnd => AssocOp::Binary(BinOpKind::And),
+      TokenKind::OrOr => AssocOp::Binary(BinOpKind::Or),
+      TokenKind::EqEq => AssocOp::Binary(BinOpKind::Eq),
+      TokenKind::NotEq => AssocOp::Binary(BinOpKind::Ne),
+      TokenKind::Lt => AssocOp::Binary(BinOpKind::Lt),
+      TokenKind::Le => AssocOp::Binary(BinOpKind::Le),
+      TokenKind::Gt => AssocOp::Binary(BinOpKind::Gt),
+      TokenKind::Ge => AssocOp::Binary(BinOpKind::Ge),
+      TokenKind::Eq => AssocOp::Assign,
+      TokenKind::DotDot => AssocOp::Range(RangeLimits::HalfOpen),
+      TokenKind::DotDotEq | TokenKind::DotDotDot => AssocOp::Range(RangeLimits::Closed),
+      _ if self.check_kw(kw::As) => AssocOp::Cast,
+
+      _ => return None,
+    };
+
+    return Some((op, 1));
+  }
+  /// The end of `a..b` or `..=b` after the operator, which is optional for `..`
+  fn parse_expr_range_rest(
+    &mut self,
+    start: Option<Box<Expr>>,
+    limits: RangeLimits,
+    op_span: Span,
+  ) -> PResult<'a, Box<Expr>> {
+    if self.prev_token.kind == TokenKind::DotDotDot {
+      self
+        .dcx()
+        .struct_span_err(op_span, "unexpected token: `...`")
+        .with_suggestion(op_span, "use `..=` for an inclusive range", "..=")
+        .emit();
+    };
+
+    let end = if self.check_range_end_start() {
+      Some(self.parse_expr_assoc_with(Bound::Excluded(ExprPrecedence::Range), Vec::new())?)
+    } else {
+      None
+    };
+    if limits == RangeLimits::Closed && end.is_none() {
+      self
+        .dcx()
+        .struct_span_err(op_span, "inclusive range with no end")
+        .with_suggestion(op_span, "use `..` instead", "..")
+        .with_note("inclusive ranges must be bounded at the end (`..=b` or `a..=b`)")
+        .emit();
+    };
+
+    let lo = start.as_ref().map_or(op_span, |start| start.span);
+    let kind = ExprKind::Range(start, end, limits);
+    return Ok(self.make_expr(Vec::new(), kind, lo.to(self.prev_token.span)));
+  }
+  /// Whether the token can start the end of a range, `a..` ends before the body in
+  /// `ushin i ishinde 0.. {}`
+  fn check_range_end_start(&self) -> bool {
+    if self.is_cond && self.check(TokenKind::OpenDelim(Delim::Brace)) {
+      return false;
+    };
+
+    return match self.token.kind {
+      TokenKind::Literal(_)
+      | TokenKind::Lifetime(..)
+      | TokenKind::OpenDelim(Delim::Paren | Delim::Brace)
+      | TokenKind::BinOp(BinOp::Minus | BinOp::Star | BinOp::And | BinOp::Or)
+      | TokenKind::Bang
+      | TokenKind::AndAnd
+      | TokenKind::OrOr
+      | TokenKind::Hashtag => true,
+      TokenKind::Ident(..) => self.check_path_start() || self.token.lit(self.kw_script).is_some(),
+
+      _ => false,
+    };
+  }
+  /// Condition of `eger`/`azirshe` or iterator of `ushin`
+  fn parse_expr_cond(&mut self) -> PResult<'a, Box<Expr>> {
+    let was_cond = std::mem::replace(&mut self.is_cond, true);
+    let cond = self.parse_expr();
+    self.is_cond = was_cond;
+
+    return cond;
+  }
+
+  /// `a < b < c` is ambiguous, so it is reported and parsed as `(a < b) < c` to continue
+  fn check_comparison_chain(&self, lhs: &Expr, op: AssocOp, op_span: Span) {
+    let ExprKind::Binary(lhs_op, ..) = &lhs.kind else {
+      return;
+    };
+    if !lhs_op.node.is_comparison() {
+      return;
+    };
+
+    let AssocOp::Binary(op) = op else {
+      return;
+    };
+    self
+      .dcx()
+      .struct_span_err(op_span, "comparison operators cannot be chained")
+      .with_label(lhs_op.span, "first comparison")
+      .with_label(op_span, "chained with this one")
+      .with_help(format!(
+        "split the comparison into two: `a {} b && b {} c`",
+        lhs_op.node.as_str(),
+        op.as_str()
+      ))
+      .emit();
+  }
+
+  /// Unary operators bind tighter than any binary operator: `-a sekildi b32` is
+  /// `(-a) sekildi b32`
+  fn parse_expr_prefix(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
+    let lo = self.token.span;
+
+    let kind = match self.token.kind {
+      TokenKind::BinOp(BinOp::Minus) => {
+        self.step();
+        ExprKind::Unary(UnOp::Neg, self.parse_expr_prefix(Vec::new())?)
+      }
+      TokenKind::Bang => {
+        self.step();
+        ExprKind::Unary(UnOp::Not, self.parse_expr_prefix(Vec::new())?)
+      }
+      TokenKind::BinOp(BinOp::Star) => {
+        self.step();
+        ExprKind::Unary(UnOp::Deref, self.parse_expr_prefix(Vec::new())?)
+      }
+      TokenKind::BinOp(BinOp::And) => {
+        self.step();
+        self.parse_expr_addr_of()?
+      }
+      TokenKind::AndAnd => {
+        // `&&a` is `& &a`
+        self.step();
+        let inner = self.parse_expr_addr_of()?;
+        let inner = self.make_expr(Vec::new(), inner, lo.to(self.prev_token.span));
+        ExprKind::AddrOf(Mutability::Nope, inner)
+      }
+
+      _ => {
+        let expr = self.parse_expr_bottom(attrs)?;
+        return self.parse_expr_postfix(expr);
+      }
+    };
+
+    return Ok(self.make_expr(attrs, kind, lo.to(self.prev_token.span)));
+  }
+  /// `&ayspaly a` after the `&`
+  fn parse_expr_addr_of(&mut self) -> PResult<'a, ExprKind> {
+    let mutability = if self.eat_kw(kw::Mut) {
+      Mutability::Mut
+    } else {
+      Mutability::Nope
+    };
+    let expr = self.parse_expr_prefix(Vec::new())?;
+
+    return Ok(ExprKind::AddrOf(mutability, expr));
+  }
+
+  /// Calls, method calls, fields, indexing and `?` bind tighter than the prefix operators:
+  /// `-a.b()` is `-(a.b())`
+  fn parse_expr_postfix(&mut self, mut expr: Box<Expr>) -> PResult<'a, Box<Expr>> {
+    loop {
+      let lo = expr.span;
+      let kind = if self.eat(TokenKind::Question) {
+        ExprKind::Try(expr)
+      } else if self.check(TokenKind::OpenDelim(Delim::Paren)) {
+        let args = self.parse_call_args()?;
+        ExprKind::Call(expr, args)
+      } else if self.eat(TokenKind::OpenDelim(Delim::Bracket)) {
+        let bracket_lo = self.prev_token.span;
+        let index = self.parse_expr()?;
+        self.expect(TokenKind::CloseDelim(Delim::Bracket))?;
+        ExprKind::Index(expr, index, bracket_lo.to(self.prev_token.span))
+      } else if self.eat(TokenKind::Dot) {
+        expr = self.parse_expr_dot(expr)?;
+        continue;
+      } else {
+        return Ok(expr);
+      };
+
+      expr = self.make_expr(Vec::new(), kind, lo.to(self.prev_token.span));
+    }
+  }
+  /// `.b`, `.b(c)` or `.0` after the `.`
+  fn parse_expr_dot(&mut self, expr: Box<Expr>) -> PResult<'a, Box<Expr>> {
+    let lo = expr.span;
+    let dot_span = self.prev_token.span;
+
+    if let TokenKind::Literal(
+      lit @ Lit {
+        kind: LitKind::Int | LitKind::Float,
+        ..
+      },
+    ) = self.token.kind
+    {
+      return self.parse_expr_tuple_field(expr, lit);
+    };
+
+    let ident = self.parse_ident()?;
+    let kind = if self.check(TokenKind::OpenDelim(Delim::Paren)) {
+      let args = self.parse_call_args()?;
+      ExprKind::MethodCall(Box::new(MethodCall {
+        seg: PathSegment {
+          ident,
+          idx: NodeIdx::DUMMY,
+        },
+        receiver: expr,
+        args,
+        span: dot_span.to(self.prev_token.span),
+      }))
+    } else {
+      ExprKind::Field(expr, ident)
+    };
+
+    return Ok(self.make_expr(Vec::new(), kind, lo.to(self.prev_token.span)));
+  }
+  /// `t.0` or `t.0.1`, which is lexed as the float `0.1`
+  fn parse_expr_tuple_field(&mut self, mut expr: Box<Expr>, lit: Lit) -> PResult<'a, Box<Expr>> {
+    let span = self.token.span;
+    let symbol = lit.symbol.as_str();
+
+    let is_index = |field: &str| !field.is_empty() && field.bytes().all(|b| b.is_ascii_digit());
+    let fields = match symbol.split_once('.') {
+      None if is_index(symbol) => vec![(symbol, span)],
+      Some((first, second)) if is_index(first) && is_index(second) => {
+        let first_hi = span.lo() + BytePos::from_usize(first.len());
+        vec![
+          (first, span.with_hi(first_hi)),
+          (second, span.with_lo(first_hi + BytePos::from_usize(1))),
+        ]
+      }
+
+      _ => {
+        return Err(
+          self
+            .dcx()
+            .struct_span_err(span, format!("unexpected token: `{lit}`"))
+            .with_label(span, "expected a tuple index like `0`"),
+        );
+      }
+    };
+    if let Some(suffix) = lit.suffix {
+      self
+        .dcx()
+        .struct_span_err(
+          span,
+          format!(
+            "suffixes on a tuple index are invalid, found `{}`",
+            suffix.as_str()
+          ),
+        )
+        .with_label(span, "invalid suffix")
+        .emit();
+    };
+    self.step();
+
+    for (field, field_span) in fields {
+      let ident = Ident {
+        name: Symbol::intern(field),
+        span: field_span,
+      };
+      let span = expr.span.to(field_span);
+      expr = self.make_expr(Vec::new(), ExprKind::Field(expr, ident), span);
+    }
+
+    return Ok(expr);
+  }
+  /// `(a, b, c)` of a call
+  fn parse_call_args(&mut self) -> PResult<'a, Vec<Expr>> {
+    self.expect(TokenKind::OpenDelim(Delim::Paren))?;
+
+    let mut args = Vec::new();
+    while !self.check(TokenKind::CloseDelim(Delim::Paren)) {
+      args.push(*self.parse_expr()?);
+
+      if !self.eat(TokenKind::Comma) {
+        break;
+      };
+    }
+    self.expect(TokenKind::CloseDelim(Delim::Paren))?;
+
+    return Ok(args);
+  }
 
-    if matches!(self.token.kind, TokenKind::Literal(..)) {
-      return self.parse_expr_lit(outer_attrs);
+  fn parse_expr_bottom(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
+    if self.token.lit(self.kw_script).is_some() {
+      return self.parse_expr_lit(attrs);
     } else if self.check_kw(kw::Let) {
-      return self.parse_expr_let(outer_attrs);
+      return self.parse_expr_let(attrs);
+    } else if self.check_kw(kw::If) {
+      return self.parse_expr_if(attrs);
+    } else if self.check_kw(kw::Match) {
+      return self.parse_expr_match(attrs);
+    } else if self.check_label() {
+      return self.parse_expr_labeled(attrs);
+    } else if self.check_closure_start() {
+      return self.parse_expr_closure(attrs);
+    } else if self.check(TokenKind::OpenDelim(Delim::Brace)) || self.check_unsafe_block() {
+      return self.parse_expr_block(attrs, None);
+    } else if [kw::For, kw::While, kw::Loop]
+      .into_iter()
+      .any(|kw| self.check_kw(kw))
+    {
+      return self.parse_expr_loop(attrs, None);
+    } else if self.check_kw(kw::Break) || self.check_kw(kw::Continue) || self.check_kw(kw::Return) {
+      return self.parse_expr_jump(attrs);
+    } else if self.check(TokenKind::OpenDelim(Delim::Paren)) {
+      return self.parse_expr_paren(attrs);
+    } else if self.check_path_start() {
+      let lo = self.token.span;
+      let path = self.parse_path()?;
+      return Ok(self.make_expr(attrs, ExprKind::Path(path), lo.to(self.prev_token.span)));
     };
 
-    todo!();
+    return Err(self.expected_err(String::from("expression")));
+  }
+  pub(crate) fn check_path_start(&self) -> bool {
+    return match self.token.ident() {
+      Some((_, true)) => true,
+      Some((ident, false)) => {
+        !ident.is_reserved(self.kw_script) || ident.is_path_segment_ident(self.kw_script)
+      }
+      None => false,
+    };
   }
 
-  fn parse_expr_lit(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
+  pub(crate) fn parse_expr_lit(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
     let lo = self.token.span;
 
-    let lit = match self.token.kind {
-      TokenKind::Literal(lit) => {
+    let lit = match self.token.lit(self.kw_script) {
+      Some(lit) => {
         self.step();
         lit
       }
-      _ => todo!(),
+      None => return Err(self.expected_err(String::from("literal"))),
     };
 
     return Ok(self.make_expr(attrs, ExprKind::Lit(lit), lo.to(self.prev_token.span)));
@@ -37,7 +612,8 @@ impl<'a> Parser<'a> {
     let pat = self.parse_pattern()?;
 
     self.expect(TokenKind::Eq)?;
-    let expr = self.parse_expr()?;
+    // `ainymaly a = b && c` is `(ainymaly a = b) && c`
+    let expr = self.parse_expr_assoc_with(Bound::Excluded(ExprPrecedence::And), Vec::new())?;
     let span = lo.to(expr.span);
 
     return Ok(Box::new(Expr {
@@ -47,7 +623,283 @@ impl<'a> Parser<'a> {
       span,
     }));
   }
-  fn make_expr(&self, attrs: Vec<Attribute>, kind: ExprKind, span: Span) -> Box<Expr> {
+  /// `(a)` or the tuple `(a, b)`, `(a,)` and `()`
+  fn parse_expr_paren(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
+    let lo = self.token.span;
+    self.expect(TokenKind::OpenDelim(Delim::Paren))?;
+
+    let mut exprs = Vec::new();
+    let mut has_trailing_comma = false;
+    while !self.check(TokenKind::CloseDelim(Delim::Paren)) {
+      exprs.push(*self.parse_expr()?);
+
+      has_trailing_comma = self.eat(TokenKind::Comma);
+      if !has_trailing_comma {
+        break;
+      };
+    }
+    self.expect(TokenKind::CloseDelim(Delim::Paren))?;
+
+    let kind = if exprs.len() == 1 && !has_trailing_comma {
+      ExprKind::Paren(Box::new(exprs.remove(0)))
+    } else {
+      ExprKind::Tuple(exprs)
+    };
+
+    return Ok(self.make_expr(attrs, kind, lo.to(self.prev_token.span)));
+  }
+
+  /// `eger a {} bolmasa eger b {} bolmasa {}`
+  fn parse_expr_if(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
+    let lo = self.token.span;
+    self.expect_kw(kw::If)?;
+
+    let cond = self.parse_expr_cond()?;
+    let then_block = self.parse_block(Safety::Inherit)?;
+    let else_expr = if !self.eat_kw(kw::Else) {
+      None
+    } else if self.check_kw(kw::If) {
+      Some(self.parse_expr_if(Vec::new())?)
+    } else {
+      Some(self.parse_expr_block(Vec::new(), None)?)
+    };
+
+    let kind = ExprKind::If(cond, then_block, else_expr);
+    return Ok(self.make_expr(attrs, kind, lo.to(self.prev_token.span)));
+  }
+  fn parse_expr_match(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
+    let lo = self.token.span;
+    self.expect_kw(kw::Match)?;
+
+    let scrutinee = self.parse_expr_cond()?;
+    self.expect(TokenKind::OpenDelim(Delim::Brace))?;
+    let mut arms = Vec::new();
+    while !self.check(TokenKind::CloseDelim(Delim::Brace)) && !self.check(TokenKind::Eof) {
+      arms.push(self.parse_arm()?);
+    }
+    self.expect(TokenKind::CloseDelim(Delim::Brace))?;
+
+    let kind = ExprKind::Match(scrutinee, arms);
+    return Ok(self.make_expr(attrs, kind, lo.to(self.prev_token.span)));
+  }
+  /// `$pat eger $guard => $expr,`, the comma is optional after a block
+  fn parse_arm(&mut self) -> PResult<'a, Arm> {
+    let attrs = self.parse_attributes(AttributeStyle::Outer)?;
+    let lo = self.token.span;
+
+    let pat = self.parse_pattern()?;
+    let guard = if self.eat_kw(kw::If) {
+      Some(self.parse_expr()?)
+    } else {
+      None
+    };
+    self.expect(TokenKind::FatArrow)?;
+
+    // Block-like bodies end at their block, like in statements
+    let body = self.parse_expr_stmt()?;
+    let span = lo.to(self.prev_token.span);
+
+    let is_last = self.check(TokenKind::CloseDelim(Delim::Brace));
+    if !self.eat(TokenKind::Comma) && !is_last && !body.kind.is_block_like() {
+      return Err(
+        self
+          .expected_err(String::from("`,`"))
+          .with_label(span, "while parsing the arm")
+          .with_help("add a comma after the arm"),
+      );
+    };
+
+    return Ok(Arm {
+      idx: NodeIdx::DUMMY,
+
+      attrs,
+      pat,
+      guard,
+      body,
+
+      span,
+    });
+  }
+
+  fn check_closure_start(&self) -> bool {
+    let is_params_start =
+      |kind: TokenKind| matches!(kind, TokenKind::BinOp(BinOp::Or) | TokenKind::OrOr);
+
+    return is_params_start(self.token.kind)
+      || (self.check_kw(kw::Move) && is_params_start(self.look_ahead(1).kind));
+  }
+  /// `kosh |a, b: b32| a + b` or `|| -> b32 { 1 }`
+  fn parse_expr_closure(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
+    let lo = self.token.span;
+
+    let capture_by = if self.eat_kw(kw::Move) {
+      CaptureBy::Value {
+        move_kw: self.prev_token.span,
+      }
+    } else {
+      CaptureBy::Ref
+    };
+
+    let decl_lo = self.token.span;
+    let mut params = Vec::new();
+    if !self.eat(TokenKind::OrOr) {
+      self.expect(TokenKind::BinOp(BinOp::Or))?;
+      while !self.eat(TokenKind::BinOp(BinOp::Or)) {
+        params.push(self.parse_closure_param()?);
+
+        if !self.eat(TokenKind::Comma) {
+          self.expect(TokenKind::BinOp(BinOp::Or))?;
+          break;
+        };
+      }
+    };
+    let return_ty = self.parse_fn_return_ty()?;
+    let fn_decl_span = decl_lo.to(self.prev_token.span);
+
+    // The body has to be a block when the return type is written
+    let body = match return_ty {
+      FnReturnType::Default => self.parse_expr()?,
+      FnReturnType::Other(_) => self.parse_expr_block(Vec::new(), None)?,
+    };
+
+    let kind = ExprKind::Closure(Box::new(Closure {
+      capture_by,
+      fn_decl: Box::new(FnDecl { params, return_ty }),
+      body,
+      fn_decl_span,
+    }));
+    return Ok(self.make_expr(attrs, kind, lo.to(self.prev_token.span)));
+  }
+  /// `a` or `a: b32`
+  fn parse_closure_param(&mut self) -> PResult<'a, Param> {
+    let lo = self.token.span;
+
+    let pat = self.parse_pattern()?;
+    let ty = if self.eat(TokenKind::Colon) {
+      self.parse_ty()?
+    } else {
+      Box::new(Ty {
+        idx: NodeIdx::DUMMY,
+        kind: TyKind::Infer,
+        span: self.prev_token.span.shrink_to_hi(),
+      })
+    };
+
+    return Ok(Param {
+      idx: NodeIdx::DUMMY,
+
+      pat,
+      ty,
+      span: lo.to(self.prev_token.span),
+    });
+  }
+
+  fn check_label(&self) -> bool {
+    return self.token.lifetime().is_some() && self.look_ahead(1).kind == TokenKind::Colon;
+  }
+  fn eat_label(&mut self) -> Option<Label> {
+    let ident = self.token.lifetime()?;
+    self.step();
+    return Some(Label { ident });
+  }
+  /// `'a: qaitala {}`
+  fn parse_expr_labeled(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
+    let label = self.eat_label();
+    self.expect(TokenKind::Colon)?;
+
+    if [kw::For, kw::While, kw::Loop]
+      .into_iter()
+      .any(|kw| self.check_kw(kw))
+    {
+      return self.parse_expr_loop(attrs, label);
+    } else if self.check(TokenKind::OpenDelim(Delim::Brace)) {
+      return self.parse_expr_block(attrs, label);
+    };
+
+    let expected = [kw::Loop, kw::While, kw::For]
+      .map(|kw| format!("`{}`", kw.kw_in(self.kw_script).as_str()))
+      .join(", ");
+    return Err(self.expected_err(format!("one of {expected} or a block after a label")));
+  }
+  /// `{}`, `'a: {}` or `qauipti {}`
+  fn parse_expr_block(
+    &mut self,
+    attrs: Vec<Attribute>,
+    label: Option<Label>,
+  ) -> PResult<'a, Box<Expr>> {
+    let lo = label.map_or(self.token.span, |label| label.ident.span);
+
+    let safety = if self.eat_kw(kw::Unsafe) {
+      Safety::Unsafe(self.prev_token.span)
+    } else {
+      Safety::Inherit
+    };
+    let block = self.parse_block(safety)?;
+
+    let kind = ExprKind::Block(block, label);
+    return Ok(self.make_expr(attrs, kind, lo.to(self.prev_token.span)));
+  }
+  /// `ushin $pat ishinde $expr {}`, `azirshe $expr {}` or `qaitala {}`
+  fn parse_expr_loop(
+    &mut self,
+    attrs: Vec<Attribute>,
+    label: Option<Label>,
+  ) -> PResult<'a, Box<Expr>> {
+    let lo = label.map_or(self.token.span, |label| label.ident.span);
+
+    let kind = if self.eat_kw(kw::For) {
+      let pat = self.parse_pattern()?;
+      self.expect_kw(kw::In)?;
+      let iter = self.parse_expr_cond()?;
+      let block = self.parse_block(Safety::Inherit)?;
+      ExprKind::ForLoop(pat, iter, block, label)
+    } else if self.eat_kw(kw::While) {
+      let cond = self.parse_expr_cond()?;
+      let block = self.parse_block(Safety::Inherit)?;
+      ExprKind::While(cond, block, label)
+    } else {
+      self.expect_kw(kw::Loop)?;
+      let block = self.parse_block(Safety::Inherit)?;
+      ExprKind::Loop(block, label)
+    };
+
+    return Ok(self.make_expr(attrs, kind, lo.to(self.prev_token.span)));
+  }
+  /// `toqta 'a $expr`, `jalgastyr 'a` or `qaitar $expr`
+  fn parse_expr_jump(&mut self, attrs: Vec<Attribute>) -> PResult<'a, Box<Expr>> {
+    let lo = self.token.span;
+
+    let kind = if self.eat_kw(kw::Break) {
+      let label = self.eat_label();
+      let expr = self.parse_jump_value()?;
+      ExprKind::Break(label, expr)
+    } else if self.eat_kw(kw::Continue) {
+      ExprKind::Continue(self.eat_label())
+    } else {
+      self.expect_kw(kw::Return)?;
+      ExprKind::Ret(self.parse_jump_value()?)
+    };
+
+    return Ok(self.make_expr(attrs, kind, lo.to(self.prev_token.span)));
+  }
+  fn parse_jump_value(&mut self) -> PResult<'a, Option<Box<Expr>>> {
+    let has_value = !matches!(
+      self.token.kind,
+      TokenKind::Semicolon
+        | TokenKind::Comma
+        | TokenKind::CloseDelim(_)
+        | TokenKind::FatArrow
+        | TokenKind::Eof
+    );
+
+    return Ok(if has_value {
+      Some(self.parse_expr()?)
+    } else {
+      None
+    });
+  }
+
+  pub(crate) fn make_expr(&self, attrs: Vec<Attribute>, kind: ExprKind, span: Span) -> Box<Expr> {
     return Box::new(Expr {
       idx: NodeIdx::DUMMY,
       attrs,
@@ -56,3 +908,113 @@ impl<'a> Parser<'a> {
     });
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use crate::tests_util::parse_expr;
+
+  #[test]
+  fn precedence() {
+    assert_eq!(parse_expr("1 + 2 * 3 - 4").0, "((1 + (2 * 3)) - 4)");
+    assert_eq!(
+      parse_expr("a || b && c == d | e ^ f & g << h").0,
+      "(a || (b && (c == (d | (e ^ (f & (g << h)))))))"
+    );
+    assert_eq!(
+      parse_expr("-a sekildi b32 * *b").0,
+      "(((-a) sekildi _) * (*b))"
+    );
+    assert_eq!(parse_expr("!&&a").0, "(!(&(&a)))");
+    assert_eq!(parse_expr("(1 + 2) * (3,)").0, "((1 + 2) * (3,))");
+  }
+
+  #[test]
+  fn associativity() {
+    assert_eq!(parse_expr("a - b - c").0, "((a - b) - c)");
+    assert_eq!(parse_expr("a = b += c >> 1").0, "(a = (b op= (c >> 1)))");
+    assert_eq!(
+      parse_expr("a sekildi b8 sekildi b16").0,
+      "((a sekildi _) sekildi _)"
+    );
+  }
+
+  #[test]
+  fn comparison_chain() {
+    assert_eq!(parse_expr("a < b").1, 0);
+    assert_eq!(
+      parse_expr("a == b < c"),
+      (String::from("((a == b) < c)"), 1)
+    );
+    assert_eq!(parse_expr("(a == b) < c").1, 0);
+  }
+
+  #[test]
+  fn postfix() {
+    assert_eq!(parse_expr("-a.b(c, d)?[0]").0, "(-a.b(c, d)?[0])");
+    assert_eq!(parse_expr("f(x)(y).z").0, "(f(x)(y).z)");
+    assert_eq!(parse_expr("t.0.1 + t.2").0, "(((t.0).1) + (t.2))");
+    assert_eq!(parse_expr("*ozi.a").0, "(*(ozi.a))");
+  }
+
+  #[test]
+  fn control_flow() {
+    assert_eq!(
+      parse_expr("eger a { b; } bolmasa eger c {} bolmasa { d; e; }").0,
+      "eger a {1} bolmasa eger c {0} bolmasa {2}"
+    );
+    assert_eq!(
+      parse_expr("saikestir a { 1 => b, _ eger c => { d; } x => toqta }").0,
+      "saikestir a {3}"
+    );
+    assert_eq!(parse_expr("'a: qaitala { toqta 'a 1 + 2; }").0, "qaitala");
+    assert_eq!(parse_expr("toqta 'a 1 + 2").0, "toqta 'a (1 + 2)");
+    assert_eq!(
+      parse_expr("ushin x ishinde xs.iter() { jalgastyr; }").0,
+      "ushin _ ishinde xs.iter()"
+    );
+    assert_eq!(parse_expr("azirshe !a { qaitar; }").0, "azirshe (!a)");
+    assert_eq!(
+      parse_expr("saikestir a {}.b() + 1").0,
+      "(saikestir a {0}.b() + 1)"
+    );
+  }
+
+  #[test]
+  fn blocks() {
+    assert_eq!(parse_expr("'a: { toqta 'a 1; }"), (String::from("{1}"), 0));
+    assert_eq!(parse_expr("qauipti { a }"), (String::from("{1}"), 0));
+    assert_eq!(
+      parse_expr("{ eger a {} { b } c }"),
+      (String::from("{3}"), 0)
+    );
+    assert_eq!(parse_expr("{ a b }"), (String::from("{2}"), 1));
+  }
+
+  #[test]
+  fn closures() {
+    assert_eq!(parse_expr("|a, b: b32| a + b").0, "|2| (a + b)");
+    assert_eq!(parse_expr("kosh || -> b32 { 1 }").0, "kosh |0| {1}");
+    assert_eq!(parse_expr("f(|g: fx(b32) -> b32,| g(1))").0, "f(|1| g(1))");
+    assert_eq!(
+      parse_expr("f sekildi qauipti fx(x: b32, b8,) -> b32").0,
+      "(f sekildi fx(2))"
+    );
+  }
+
+  #[test]
+  fn ranges() {
+    assert_eq!(parse_expr("a..b + 1").0, "(a..(b + 1))");
+    assert_eq!(parse_expr("x = ..=b || c").0, "(x = (..=(b || c)))");
+    assert_eq!(parse_expr("f(a.., ..)").0, "f((a..), (..))");
+    assert_eq!(
+      parse_expr("ushin i ishinde 0..n {}").0,
+      "ushin _ ishinde (0..n)"
+    );
+    assert_eq!(
+      parse_expr("ushin i ishinde 0.. {}").0,
+      "ushin _ ishinde (0..)"
+    );
+    assert_eq!(parse_expr("a..b..c").1, 1);
+    assert_eq!(parse_expr("a..=").1, 1);
+  }
+}
@@ -1,5 +1,6 @@
 use tilc_ast::{
-  Block, Delim, FnDecl, FnHeader, FnReturnType, Generics, NodeIdx, TokenKind, TyKind,
+  BinOp, Block, Delim, FnDecl, FnHeader, FnReturnType, MutTy, Mutability, NodeIdx, Param, Pattern,
+  PatternKind, Safety, TokenKind, Ty, TyKind,
 };
 use tilc_error::PResult;
 use tilc_span::kw;
@@ -21,61 +22,179 @@ impl<'a> Parser<'a> {
     return Ok(FnHeader { is_const, is_async });
   }
   pub(crate) fn parse_fn_decl(&mut self) -> PResult<'a, FnDecl> {
+    let params = self.parse_fn_params(|this, is_first| this.parse_fn_param(is_first))?;
+    let return_ty = self.parse_fn_return_ty()?;
+
+    return Ok(FnDecl { params, return_ty });
+  }
+  /// `(param, param, ...)` with an optional trailing comma, where `...` can only be the last one
+  pub(crate) fn parse_fn_params(
+    &mut self,
+    mut parse_param: impl FnMut(&mut Self, bool) -> PResult<'a, Param>,
+  ) -> PResult<'a, Vec<Param>> {
     self.expect(TokenKind::OpenDelim(Delim::Paren))?;
 
     let mut params = Vec::new();
-    if !self.eat(TokenKind::CloseDelim(Delim::Paren)) {
-      // self.parse_inside_delim(Delim::Paren, |this: &mut Self| {
-      // return Ok(());
-      // })?;
-      todo!()
+    while !self.eat(TokenKind::CloseDelim(Delim::Paren)) {
+      params.push(parse_param(self, params.is_empty())?);
+
+      if !self.eat(TokenKind::Comma) {
+        self.expect(TokenKind::CloseDelim(Delim::Paren))?;
+        break;
+      };
+    }
+
+    let non_last = params.split_last().map_or(&[][..], |(_, init)| init);
+    for param in non_last
+      .iter()
+      .filter(|param| matches!(param.ty.kind, TyKind::VarArgs))
+    {
+      self
+        .dcx()
+        .struct_span_err(param.span, "`...` must be the last parameter")
+        .emit();
+    }
+
+    return Ok(params);
+  }
+  /// `x: b32`, `(a, b): (b32, b32)`, `...` or a receiver as the first parameter
+  fn parse_fn_param(&mut self, is_first: bool) -> PResult<'a, Param> {
+    if let Some(param) = self.parse_self_param()? {
+      if !is_first {
+        let self_kw = kw::SelfValue.kw_in(self.kw_script);
+        self
+          .dcx()
+          .struct_span_err(
+            param.span,
+            format!(
+              "`{}` parameter is only allowed as the first parameter",
+              self_kw.as_str()
+            ),
+          )
+          .with_label(param.span, "not the first parameter")
+          .emit();
+      };
+      return Ok(param);
     };
 
-    let return_ty = self.parse_fn_return_ty()?;
+    let lo = self.token.span;
+    let pat = if self.check(TokenKind::DotDotDot) {
+      Box::new(Pattern {
+        idx: NodeIdx::DUMMY,
+        kind: PatternKind::Wild,
+        span: lo.shrink_to_lo(),
+      })
+    } else {
+      let pat = self.parse_pattern()?;
+      self.expect(TokenKind::Colon)?;
+      pat
+    };
+    let ty = self.parse_param_ty()?;
 
-    return Ok(FnDecl { params, return_ty });
+    return Ok(Param {
+      idx: NodeIdx::DUMMY,
+
+      pat,
+      ty,
+      span: lo.to(self.prev_token.span),
+    });
   }
-  pub(crate) fn parse_fn_generics(&mut self) -> PResult<'a, Generics> {
-    if !self.eat(TokenKind::Lt) {
-      return Ok(Generics {
-        params: Vec::new(),
-        span: self.prev_token.span.shrink_to_hi(),
-      });
+  /// Type of a parameter, which can also be `...`
+  pub(crate) fn parse_param_ty(&mut self) -> PResult<'a, Box<Ty>> {
+    if !self.eat(TokenKind::DotDotDot) {
+      return self.parse_ty();
     };
 
-    todo!()
+    return Ok(Box::new(Ty {
+      idx: NodeIdx::DUMMY,
+      kind: TyKind::VarArgs,
+      span: self.prev_token.span,
+    }));
   }
-  pub(crate) fn parse_fn_body(&mut self) -> PResult<'a, Option<Block>> {
-    if self.check(TokenKind::Semicolon) {
-      return Ok(None);
+  /// `ozi`, `ayspaly ozi`, `&ozi`, `&'a ayspaly ozi` or `ozi: Ozi` (if any)
+  fn parse_self_param(&mut self) -> PResult<'a, Option<Param>> {
+    let is_self_at = |this: &Self, n: usize| this.token_is_kw(this.look_ahead(n), kw::SelfValue);
+    let is_mut_self_at = |this: &Self, n: usize| {
+      is_self_at(this, n)
+        || (this.token_is_kw(this.look_ahead(n), kw::Mut) && is_self_at(this, n + 1))
     };
     let lo = self.token.span;
 
-    self.expect(TokenKind::OpenDelim(Delim::Brace))?;
-
-    let mut statements = Vec::new();
-    while !self.eat(TokenKind::CloseDelim(Delim::Brace)) {
-      if self.check(TokenKind::Eof) {
-        break;
+    let reference = if self.check(TokenKind::BinOp(BinOp::And)) {
+      let lifetime_len = if self.look_ahead(1).lifetime().is_some() {
+        1
+      } else {
+        0
+      };
+      if !is_mut_self_at(self, 1 + lifetime_len) {
+        return Ok(None);
       };
 
-      let statement = self.parse_statement()?;
-
-      if let Some(statement) = statement {
-        statements.push(statement);
+      self.step();
+      let lifetime = self.eat_lifetime();
+      let mutability = if self.eat_kw(kw::Mut) {
+        Mutability::Mut
       } else {
-        // Skip repetive ';' (if any)
-        continue;
+        Mutability::Nope
       };
-    }
+      Some((lifetime, mutability))
+    } else if is_mut_self_at(self, 0) {
+      None
+    } else {
+      return Ok(None);
+    };
+
+    let pat_mutability = if reference.is_none() && self.eat_kw(kw::Mut) {
+      Mutability::Mut
+    } else {
+      Mutability::Nope
+    };
+    let Some((self_ident, _)) = self.expect_kw(kw::SelfValue)?.ident() else {
+      unreachable!();
+    };
 
-    return Ok(Some(Block {
+    let implicit_ty = Box::new(Ty {
+      idx: NodeIdx::DUMMY,
+      kind: TyKind::ImplicitSelf,
+      span: self_ident.span,
+    });
+    let ty = match reference {
+      Some((lifetime, mutability)) => Box::new(Ty {
+        idx: NodeIdx::DUMMY,
+        kind: TyKind::Ref(
+          lifetime,
+          MutTy {
+            ty: implicit_ty,
+            mutability,
+          },
+        ),
+        span: lo.to(self_ident.span),
+      }),
+      None if self.eat(TokenKind::Colon) => self.parse_ty()?,
+      None => implicit_ty,
+    };
+    let pat = Box::new(Pattern {
+      idx: NodeIdx::DUMMY,
+      kind: PatternKind::Ident(pat_mutability, self_ident, None),
+      span: self_ident.span,
+    });
+
+    return Ok(Some(Param {
       idx: NodeIdx::DUMMY,
 
-      statements,
+      pat,
+      ty,
       span: lo.to(self.prev_token.span),
     }));
   }
+  pub(crate) fn parse_fn_body(&mut self) -> PResult<'a, Option<Block>> {
+    if self.eat(TokenKind::Semicolon) {
+      return Ok(None);
+    };
+
+    let block = self.parse_block(Safety::Inherit)?;
+    return Ok(Some(*block));
+  }
   pub(crate) fn parse_fn_return_ty(&mut self) -> PResult<'a, FnReturnType> {
     if !self.eat(TokenKind::RArrow) {
       return Ok(FnReturnType::Default);
@@ -91,3 +210,88 @@ impl<'a> Parser<'a> {
     return Ok(FnReturnType::Other(return_ty));
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use tilc_ast::{AssociatedItemKind, ItemKind, MutTy, Mutability, PatternKind, TyKind};
+
+  use crate::tests_util::parse_items;
+
+  #[test]
+  fn fn_params() {
+    let (items, errors) = parse_items(
+      "fx f(x: b32, (a, b): (b32, q32), &c: &b8, ayspaly d: b32,) {}
+      asyru A {
+        fx a(ozi) {}
+        fx b(&ozi, x: b32) {}
+        fx c(&'a ayspaly ozi,) {}
+        fx d(ayspaly ozi: Ozi) {}
+      }
+      fx e(format: &b8, ...);",
+    );
+    assert_eq!(errors, 0);
+    let ItemKind::Fn(function) = &items[0].kind else {
+      unreachable!();
+    };
+    let params = &function.fn_sig.fn_decl.params;
+    assert_eq!(params.len(), 4);
+    assert!(matches!(&params[1].pat.kind, PatternKind::Tuple(pats) if pats.len() == 2));
+    assert!(matches!(&params[1].ty.kind, TyKind::Tuple(tys) if tys.len() == 2));
+    assert!(matches!(
+      params[2].pat.kind,
+      PatternKind::Ref(Mutability::Nope, _)
+    ));
+
+    let ItemKind::Impl(impl_item) = &items[1].kind else {
+      unreachable!();
+    };
+    let receivers = impl_item
+      .items
+      .iter()
+      .map(|item| match &item.kind {
+        AssociatedItemKind::Fn(function) => &function.fn_sig.fn_decl.params[0],
+        _ => unreachable!(),
+      })
+      .collect::<Vec<_>>();
+    assert!(matches!(receivers[0].ty.kind, TyKind::ImplicitSelf));
+    assert!(matches!(
+      &receivers[1].ty.kind,
+      TyKind::Ref(
+        None,
+        MutTy {
+          mutability: Mutability::Nope,
+          ..
+        }
+      )
+    ));
+    assert!(matches!(
+      &receivers[2].ty.kind,
+      TyKind::Ref(
+        Some(_),
+        MutTy {
+          mutability: Mutability::Mut,
+          ..
+        }
+      )
+    ));
+    assert!(matches!(
+      receivers[3].pat.kind,
+      PatternKind::Ident(Mutability::Mut, ..)
+    ));
+    assert!(matches!(receivers[3].ty.kind, TyKind::Path(_)));
+
+    let ItemKind::Fn(function) = &items[2].kind else {
+      unreachable!();
+    };
+    assert!(matches!(
+      function.fn_sig.fn_decl.params[1].ty.kind,
+      TyKind::VarArgs
+    ));
+
+    assert_eq!(parse_items("fx f(x: b32, ozi) {}").1, 1);
+    assert_eq!(parse_items("fx f(..., x: b32);").1, 1);
+    assert_eq!(parse_items("fx f(x: b32, ...) {}").1, 1);
+    assert_eq!(parse_items("fx f(x: ) {}").1, 1);
+    assert_eq!(parse_items("fx f() -> { }").1, 1);
+  }
+}
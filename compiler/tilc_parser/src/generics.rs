@@ -0,0 +1,126 @@
+use tilc_ast::{
+  BinOp, GenericBound, GenericParam, GenericParamKind, Generics, Lifetime, NodeIdx, TokenKind,
+};
+use tilc_error::PResult;
+use tilc_span::kw;
+
+use crate::Parser;
+
+impl<'a> Parser<'a> {
+  /// Parses `<'a, 'b: 'a, T: Trait + 'a, turaqty N: b32>` (if any)
+  pub(crate) fn parse_generics(&mut self) -> PResult<'a, Generics> {
+    if !self.check(TokenKind::Lt) {
+      return Ok(Generics {
+        params: Vec::new(),
+        span: self.prev_token.span.shrink_to_hi(),
+      });
+    };
+    let lo = self.token.span;
+    self.step();
+
+    let mut params = Vec::new();
+    while !self.eat(TokenKind::Gt) {
+      params.push(self.parse_generic_param()?);
+
+      if !self.eat(TokenKind::Comma) {
+        self.expect(TokenKind::Gt)?;
+        break;
+      };
+    }
+
+    return Ok(Generics {
+      params,
+      span: lo.to(self.prev_token.span),
+    });
+  }
+  fn parse_generic_param(&mut self) -> PResult<'a, GenericParam> {
+    if let Some(lifetime) = self.eat_lifetime() {
+      // 'a: 'b + 'c
+      let mut bounds = Vec::new();
+      if self.eat(TokenKind::Colon) {
+        loop {
+          match self.eat_lifetime() {
+            Some(bound) => bounds.push(GenericBound::Outlives(bound)),
+            None => {
+              let span = self.token.span;
+              return Err(
+                self
+                  .dcx()
+                  .struct_span_err(span, "lifetime parameters can only be bounded by lifetimes")
+                  .with_label(span, "expected a lifetime"),
+              );
+            }
+          };
+
+          if !self.eat(TokenKind::BinOp(BinOp::Plus)) {
+            break;
+          };
+        }
+      };
+
+      return Ok(GenericParam {
+        idx: NodeIdx::DUMMY,
+        ident: lifetime.ident,
+        bounds,
+        kind: GenericParamKind::Lifetime,
+      });
+    };
+
+    if self.eat_kw(kw::Const) {
+      // turaqty N: b32
+      let ident = self.parse_ident()?;
+      self.expect(TokenKind::Colon)?;
+      let ty = self.parse_ty()?;
+
+      return Ok(GenericParam {
+        idx: NodeIdx::DUMMY,
+        ident,
+        bounds: Vec::new(),
+        kind: GenericParamKind::Const { ty },
+      });
+    };
+
+    // T: Trait + 'a
+    let ident = self.parse_ident()?;
+    let bounds = if self.eat(TokenKind::Colon) {
+      self.parse_generic_bounds()?
+    } else {
+      Vec::new()
+    };
+
+    return Ok(GenericParam {
+      idx: NodeIdx::DUMMY,
+      ident,
+      bounds,
+      kind: GenericParamKind::Type,
+    });
+  }
+  pub(crate) fn parse_generic_bounds(&mut self) -> PResult<'a, Vec<GenericBound>> {
+    let mut bounds = Vec::new();
+    loop {
+      if let Some(lifetime) = self.eat_lifetime() {
+        bounds.push(GenericBound::Outlives(lifetime));
+      } else if self.token.ident().is_some() {
+        bounds.push(GenericBound::Trait(self.parse_path()?));
+      } else {
+        break;
+      };
+
+      if !self.eat(TokenKind::BinOp(BinOp::Plus)) {
+        break;
+      };
+    }
+
+    return Ok(bounds);
+  }
+
+  pub(crate) fn eat_lifetime(&mut self) -> Option<Lifetime> {
+    let ident = self.token.lifetime()?;
+    self.step();
+
+    return Some(Lifetime {
+      idx: NodeIdx::DUMMY,
+      ident,
+    });
+  }
+}
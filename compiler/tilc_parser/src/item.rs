@@ -1,9 +1,10 @@
 use tilc_ast::{
-  Attribute, AttributeStyle, Delim, Fn, FnSig, Item, ItemKind, NodeIdx, Sandyq, TokenKind, Ty,
-  TyKind, Vis, VisKind,
+  Attribute, AttributeStyle, BinOp, Delim, Fn, FnDecl, FnPtrTy, FnSig, Item, ItemKind, Korpe,
+  MutTy, Mutability, NodeIdx, Param, Pattern, PatternKind, Safety, Sandyq, TokenKind, Ty, TyKind,
+  Vis, VisKind,
 };
 use tilc_error::PResult;
-use tilc_span::{Ident, Span, Symbol, kw};
+use tilc_span::{Ident, Span, Symbol, kw, sym};
 
 use crate::Parser;
 
@@ -12,6 +13,7 @@ pub(crate) type ItemInfo = (Ident, ItemKind);
 impl<'a> Parser<'a> {
   pub fn parse_sandyq(&mut self) -> PResult<'a, Sandyq> {
     let (items, attrs, span) = self.parse_until(TokenKind::Eof)?;
+    self.check_keyword_script_attrs(&attrs, true);
 
     Ok(Sandyq {
       idx: NodeIdx::DUMMY,
@@ -36,24 +38,40 @@ impl<'a> Parser<'a> {
 
     let span = lo.to(self.prev_token.span);
     if !self.eat(stopper) {
-      todo!();
+      return Err(self.expected_err(String::from("item")));
     };
 
     Ok((items, attrs, span))
   }
 
   pub(crate) fn parse_item(&mut self) -> PResult<'a, Option<Item>> {
+    let mut attrs = self.parse_attributes(AttributeStyle::Outer)?;
     let lo = self.token.span;
-    let vis = self.parse_vis()?;
+    let vis = self.parse_vis(false)?;
 
-    let Some((ident, kind)) = self.parse_item_info(lo)? else {
+    let Some((ident, kind)) = self.parse_item_info(lo, &vis, &mut attrs)? else {
+      if let Some(last_attr) = attrs.last() {
+        let msg = if last_attr.path.is_ident(sym::Doc) {
+          "expected item after doc comment"
+        } else {
+          "expected item after attributes"
+        };
+        self
+          .dcx()
+          .struct_span_err(last_attr.span, msg)
+          .with_label(
+            self.token.span,
+            "other attributes here must precede an item",
+          )
+          .emit();
+      };
       return Ok(None);
     };
 
     Ok(Some(Item {
       idx: NodeIdx::DUMMY,
 
-      attrs: Vec::new(),
+      attrs,
       vis,
       kind,
       ident,
@@ -61,7 +79,13 @@ impl<'a> Parser<'a> {
       span: lo.to(self.prev_token.span),
     }))
   }
-  fn parse_item_info(&mut self, start_span: Span) -> PResult<'a, Option<ItemInfo>> {
+  /// Inner attributes of the item (`korpe a { #![attr] }`) are pushed to `attrs`
+  fn parse_item_info(
+    &mut self,
+    start_span: Span,
+    vis: &Vis,
+    attrs: &mut Vec<Attribute>,
+  ) -> PResult<'a, Option<ItemInfo>> {
     if self.check(TokenKind::Eof) {
       return Ok(None);
     };
@@ -70,6 +94,18 @@ impl<'a> Parser<'a> {
       self.parse_use_item()?
     } else if self.check_for_fn_item() {
       self.parse_fn_item(start_span)?
+    } else if self.eat_kw(kw::Struct) {
+      self.parse_struct_item()?
+    } else if self.eat_kw(kw::Union) {
+      self.parse_union_item()?
+    } else if self.eat_kw(kw::Enum) {
+      self.parse_enum_item()?
+    } else if self.check_maybe_unsafe_kw(kw::Trait) {
+      self.parse_trait_item(start_span, vis)?
+    } else if self.check_maybe_unsafe_kw(kw::Impl) {
+      self.parse_impl_item(start_span)?
+    } else if self.check_maybe_unsafe_kw(kw::Mod) {
+      self.parse_korpe_item(attrs)?
     } else {
       return Ok(None);
     };
@@ -77,15 +113,28 @@ impl<'a> Parser<'a> {
     Ok(Some(item_info))
   }
 
-  fn parse_fn_item(&mut self, start_span: Span) -> PResult<'a, ItemInfo> {
+  pub(crate) fn parse_fn_item(&mut self, start_span: Span) -> PResult<'a, ItemInfo> {
     let fn_header = self.parse_fn_header()?;
     let fn_identifier = self.parse_ident()?;
-    let fn_generics = self.parse_fn_generics()?;
+    let fn_generics = self.parse_generics()?;
     let fn_decl = self.parse_fn_decl()?;
 
     let sig_hi = self.prev_token.span;
     let fn_body = self.parse_fn_body()?;
 
+    if let Some(body) = &fn_body
+      && let Some(param) = fn_decl
+        .params
+        .iter()
+        .find(|param| matches!(param.ty.kind, TyKind::VarArgs))
+    {
+      self
+        .dcx()
+        .struct_span_err(param.span, "only foreign functions may be variadic")
+        .with_label(body.span, "the function has a body")
+        .emit();
+    };
+
     Ok((
       fn_identifier.clone(),
       ItemKind::Fn(Box::new(Fn {
@@ -100,8 +149,28 @@ impl<'a> Parser<'a> {
       })),
     ))
   }
+  /// `korpe a;` or `korpe a { ... }`
+  fn parse_korpe_item(&mut self, attrs: &mut Vec<Attribute>) -> PResult<'a, ItemInfo> {
+    let safety = self.parse_safety();
+    self.expect_kw(kw::Mod)?;
+    let ident = self.parse_ident()?;
+
+    let korpe = if self.eat(TokenKind::Semicolon) {
+      Korpe::File
+    } else if self.eat(TokenKind::OpenDelim(Delim::Brace)) {
+      let (items, inner_attrs, span) = self.parse_until(TokenKind::CloseDelim(Delim::Brace))?;
+      self.check_keyword_script_attrs(&inner_attrs, false);
+
+      attrs.extend(inner_attrs);
+      Korpe::Braced(items, span)
+    } else {
+      return Err(self.expected_err(String::from("`{` or `;`")));
+    };
+
+    Ok((ident, ItemKind::Korpe(safety, ident, korpe)))
+  }
   fn parse_use_item(&mut self) -> PResult<'a, ItemInfo> {
-    debug_assert!(self.prev_token.is_kw(kw::Use));
+    debug_assert!(self.token_is_kw(self.prev_token, kw::Use));
     let use_path = self.parse_use_path()?;
     self.expect(TokenKind::Semicolon)?;
 
@@ -112,28 +181,102 @@ impl<'a> Parser<'a> {
     let (ident, raw) = match self.token.ident() {
       Some((ident, raw)) => (ident, raw),
       None => {
-        // TODO: Error handling
-        todo!();
+        let span = self.token.span;
+        return Err(
+          self
+            .dcx()
+            .struct_span_err(span, "expected identifier")
+            .with_label(span, "expected identifier"),
+        );
       }
     };
-    if !raw && ident.is_reserved() {
-      todo!();
+
+    if raw && !ident.can_be_raw(self.kw_script) {
+      self
+        .dcx()
+        .struct_span_err(
+          ident.span,
+          format!("`{}` cannot be a raw identifier", ident.name.as_str()),
+        )
+        .emit();
+    } else if !raw && ident.is_reserved(self.kw_script) {
+      let name = ident.name.as_str();
+      self
+        .dcx()
+        .struct_span_err(
+          ident.span,
+          format!("expected identifier, found keyword `{name}`"),
+        )
+        .with_suggestion(
+          ident.span,
+          format!("escape `{name}` to use it as an identifier"),
+          format!("s#{name}"),
+        )
+        .emit();
     };
 
     self.step();
     Ok(ident)
   }
 
-  fn parse_vis(&mut self) -> PResult<'a, Vis> {
+  /// `barsha`, `barsha(sandyq)`, `barsha(super)`, `barsha(ozi)` or `barsha(ishinde a::b)`
+  ///
+  /// `followed_by_ty` is set for tuple fields, where `barsha (b8, b8)` is a public tuple
+  pub(crate) fn parse_vis(&mut self, followed_by_ty: bool) -> PResult<'a, Vis> {
     if !self.eat_kw(kw::Pub) {
       return Ok(Vis {
         kind: VisKind::Private,
         span: self.prev_token.span.shrink_to_hi(),
       });
     };
+    let lo = self.prev_token.span;
 
-    if self.eat(TokenKind::OpenDelim(Delim::Paren)) {
-      todo!();
+    if self.check(TokenKind::OpenDelim(Delim::Paren)) {
+      let is_path_kw = [kw::Sandyq, kw::Super, kw::SelfValue]
+        .iter()
+        .any(|&kw| self.token_is_kw(self.look_ahead(1), kw));
+
+      if self.token_is_kw(self.look_ahead(1), kw::In) {
+        self.step();
+        self.step();
+        return self.parse_vis_restricted(lo);
+      } else if is_path_kw && self.look_ahead(2).kind == TokenKind::CloseDelim(Delim::Paren) {
+        self.step();
+        return self.parse_vis_restricted(lo);
+      } else if !followed_by_ty {
+        // `barsha(a::b)` is most likely meant as `barsha(ishinde a::b)`
+        self.step();
+        let vis = self.parse_vis_restricted(lo)?;
+        let VisKind::Protected(_, path) = &vis.kind else {
+          unreachable!();
+        };
+
+        let kw_str = |kw: Symbol| kw.kw_in(self.kw_script).as_str();
+        let path_str = path
+          .segments
+          .iter()
+          .map(|segment| segment.ident.name.as_str())
+          .collect::<Vec<_>>()
+          .join("::");
+        self
+          .dcx()
+          .struct_span_err(path.span, "incorrect visibility restriction")
+          .with_help(format!(
+            "some possible visibility restrictions are `{pub_kw}({})`, `{pub_kw}({})`, `{pub_kw}({})` and `{pub_kw}({} path)`",
+            kw_str(kw::Sandyq),
+            kw_str(kw::Super),
+            kw_str(kw::SelfValue),
+            kw_str(kw::In),
+            pub_kw = kw_str(kw::Pub),
+          ))
+          .with_suggestion(
+            path.span,
+            format!("make this visible only to korpe `{path_str}`"),
+            format!("{} {path_str}", kw_str(kw::In)),
+          )
+          .emit();
+        return Ok(vis);
+      };
     };
 
     Ok(Vis {
@@ -142,19 +285,55 @@ impl<'a> Parser<'a> {
     })
   }
 
-  fn check_for_fn_item(&self) -> bool {
+  /// The path and the `)` of `barsha(sandyq)` or `barsha(ishinde a::b)`
+  fn parse_vis_restricted(&mut self, lo: Span) -> PResult<'a, Vis> {
+    let path = self.parse_path()?;
+    self.expect(TokenKind::CloseDelim(Delim::Paren))?;
+
+    return Ok(Vis {
+      kind: VisKind::Protected(NodeIdx::DUMMY, Box::new(path)),
+      span: lo.to(self.prev_token.span),
+    });
+  }
+
+  pub(crate) fn check_for_fn_item(&self) -> bool {
     // TODO: Update the list after adding keywords (if necessary)
     // const POSSIBILITIES: &[Symbol; 3] = &[kw::Const, kw::Extern, kw::Async];
     const POSSIBILITIES: &[Symbol; 1] = &[kw::Const];
 
     self.check_kw(kw::Function)
-      || (POSSIBILITIES.iter().any(|s| self.check_kw(*s)) && self.look_ahead(1).is_kw(kw::Function))
+      || (POSSIBILITIES.iter().any(|s| self.check_kw(*s))
+        && self.token_is_kw(self.look_ahead(1), kw::Function))
   }
 
   pub(crate) fn parse_ty(&mut self) -> PResult<'a, Box<Ty>> {
     let lo = self.token.span;
 
-    let ty_kind = if self.eat(TokenKind::Bang) {
+    let ty_kind = if self.eat(TokenKind::BinOp(BinOp::And)) {
+      self.parse_ty_ref()?
+    } else if self.eat(TokenKind::AndAnd) {
+      // `&&T` is `& &T`
+      let inner_lo = self.prev_token.span;
+      let inner = self.parse_ty_ref()?;
+      let inner = Box::new(Ty {
+        idx: NodeIdx::DUMMY,
+        kind: inner,
+        span: inner_lo.to(self.prev_token.span),
+      });
+      TyKind::Ref(
+        None,
+        MutTy {
+          ty: inner,
+          mutability: Mutability::Nope,
+        },
+      )
+    } else if self.check_kw(kw::Function)
+      || (self.check_kw(kw::Unsafe) && self.token_is_kw(self.look_ahead(1), kw::Function))
+    {
+      self.parse_ty_fn_ptr()?
+    } else if self.check(TokenKind::OpenDelim(Delim::Paren)) {
+      self.parse_ty_tuple()?
+    } else if self.eat(TokenKind::Bang) {
       TyKind::Never
     } else if self.eat_kw(kw::Underscore) {
       TyKind::Infer
@@ -162,7 +341,7 @@ impl<'a> Parser<'a> {
       let path = Box::new(self.parse_path()?);
       TyKind::Path(path)
     } else {
-      todo!()
+      return Err(self.expected_err(String::from("type")));
     };
 
     Ok(Box::new(Ty {
@@ -171,4 +350,118 @@ impl<'a> Parser<'a> {
       span: lo.to(self.prev_token.span),
     }))
   }
+  /// `fx(b32, b32) -> b32` or `qauipti fx(x: b32)`
+  fn parse_ty_fn_ptr(&mut self) -> PResult<'a, TyKind> {
+    let safety = if self.eat_kw(kw::Unsafe) {
+      Safety::Unsafe(self.prev_token.span)
+    } else {
+      Safety::Inherit
+    };
+
+    let decl_lo = self.token.span;
+    self.expect_kw(kw::Function)?;
+    let params = self.parse_fn_params(|this, _| this.parse_fn_ptr_param())?;
+    let return_ty = self.parse_fn_return_ty()?;
+
+    return Ok(TyKind::FnPtr(Box::new(FnPtrTy {
+      safety,
+      decl: Box::new(FnDecl { params, return_ty }),
+      decl_span: decl_lo.to(self.prev_token.span),
+    })));
+  }
+  /// `b32`, `...` or the named `x: b32`, an unnamed parameter gets the `_` pattern
+  fn parse_fn_ptr_param(&mut self) -> PResult<'a, Param> {
+    let lo = self.token.span;
+
+    let is_named = self.token.ident().is_some() && self.look_ahead(1).kind == TokenKind::Colon;
+    let pat = if is_named {
+      let pat = self.parse_pattern()?;
+      self.expect(TokenKind::Colon)?;
+      pat
+    } else {
+      Box::new(Pattern {
+        idx: NodeIdx::DUMMY,
+        kind: PatternKind::Wild,
+        span: lo.shrink_to_lo(),
+      })
+    };
+    let ty = self.parse_param_ty()?;
+
+    return Ok(Param {
+      idx: NodeIdx::DUMMY,
+
+      pat,
+      ty,
+      span: lo.to(self.prev_token.span),
+    });
+  }
+  /// `(A, B)`, `(A,)`, `()` or the parenthesized `(A)`
+  fn parse_ty_tuple(&mut self) -> PResult<'a, TyKind> {
+    self.expect(TokenKind::OpenDelim(Delim::Paren))?;
+
+    let mut tys = Vec::new();
+    let mut trailing_comma = false;
+    while !self.eat(TokenKind::CloseDelim(Delim::Paren)) {
+      tys.push(*self.parse_ty()?);
+
+      trailing_comma = self.eat(TokenKind::Comma);
+      if !trailing_comma {
+        self.expect(TokenKind::CloseDelim(Delim::Paren))?;
+        break;
+      };
+    }
+
+    if tys.len() == 1 && !trailing_comma {
+      return Ok(tys.remove(0).kind);
+    };
+    return Ok(TyKind::Tuple(tys));
+  }
+  /// `&'a ayspaly T` after the `&`
+  fn parse_ty_ref(&mut self) -> PResult<'a, TyKind> {
+    let lifetime = self.eat_lifetime();
+    let mutability = if self.eat_kw(kw::Mut) {
+      Mutability::Mut
+    } else {
+      Mutability::Nope
+    };
+    let ty = self.parse_ty()?;
+
+    return Ok(TyKind::Ref(lifetime, MutTy { ty, mutability }));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use tilc_ast::{ItemKind, TyKind, VariantKind, Vis, VisKind};
+
+  use crate::tests_util::parse_items;
+
+  #[test]
+  fn restricted_visibility() {
+    let (items, errors) = parse_items(
+      "barsha(sandyq) fx a() {}
+      barsha(ishinde a::b) qurylym B(barsha (b8, b8), barsha(ozi) b8);
+      qurylym C { barsha(super) x: b8 }",
+    );
+    assert_eq!(errors, 0);
+    let restricted_to = |vis: &Vis| match &vis.kind {
+      VisKind::Protected(_, path) => path.segments.len(),
+      _ => 0,
+    };
+    assert_eq!(restricted_to(&items[0].vis), 1);
+    assert_eq!(restricted_to(&items[1].vis), 2);
+    let ItemKind::Struct(_, _, VariantKind::Tuple(fields)) = &items[1].kind else {
+      unreachable!();
+    };
+    assert!(matches!(fields[0].vis.kind, VisKind::Public));
+    assert!(matches!(fields[0].ty.kind, TyKind::Tuple(_)));
+    assert_eq!(restricted_to(&fields[1].vis), 1);
+    let ItemKind::Struct(_, _, VariantKind::Struct(fields)) = &items[2].kind else {
+      unreachable!();
+    };
+    assert_eq!(restricted_to(&fields[0].vis), 1);
+
+    assert_eq!(parse_items("barsha(a) fx d() {}").1, 1);
+    assert_eq!(parse_items("qurylym A { x: }").1, 1);
+  }
 }
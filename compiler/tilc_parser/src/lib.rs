@@ -1,17 +1,26 @@
+mod adt;
 mod atrribute;
 mod expr;
 mod function;
+mod generics;
 mod item;
 mod parser;
 mod path;
 mod pattern;
 mod statement;
+mod traits;
 
+#[cfg(test)]
+mod tests_util;
+
+pub use adt::*;
 pub use atrribute::*;
 pub use expr::*;
 pub use function::*;
+pub use generics::*;
 pub use item::*;
 pub use parser::*;
 pub use path::*;
 pub use pattern::*;
 pub use statement::*;
+pub use traits::*;
@@ -1,7 +1,7 @@
 use tilc_ast::{Delim, Spacing, Token, TokenCursor, TokenKind, TokenStream};
-use tilc_error::PResult;
+use tilc_error::{Diag, DiagCtxtHandle, PResult};
 use tilc_session::ParseSession;
-use tilc_span::Symbol;
+use tilc_span::{KeywordScript, Symbol};
 
 #[derive(Debug)]
 pub struct Parser<'psess> {
@@ -9,14 +9,26 @@ pub struct Parser<'psess> {
 
   pub(crate) token: Token,
   pub(crate) prev_token: Token,
-  token_spacing: Spacing,
+  /// Whether the token is directly followed by the next one
+  pub(crate) token_spacing: Spacing,
+
+  /// Script of the keywords of the file, decided by `#![kilt_sozder = "..."]` of its header
+  /// before lexing, since the lexer already needs it
+  pub(crate) kw_script: KeywordScript,
+  /// Set while parsing the condition of `eger`/`azirshe` and the iterator of `ushin`, where
+  /// `{` starts the body: `ushin i ishinde 0.. {}`
+  pub(crate) is_cond: bool,
 
   token_cursor: TokenCursor,
 
   pos: u32,
 }
 impl<'psess> Parser<'psess> {
-  pub fn new(psess: &'psess ParseSession, token_stream: TokenStream) -> Self {
+  pub fn new(
+    psess: &'psess ParseSession,
+    token_stream: TokenStream,
+    kw_script: KeywordScript,
+  ) -> Self {
     let mut parser = Self {
       psess,
 
@@ -24,6 +36,9 @@ impl<'psess> Parser<'psess> {
       prev_token: Token::DUMMY,
       token_spacing: Spacing::Whitespaced,
 
+      kw_script,
+      is_cond: false,
+
       token_cursor: TokenCursor::new(token_stream.into_tree(), Vec::new()),
 
       pos: 0,
@@ -35,6 +50,10 @@ impl<'psess> Parser<'psess> {
     parser
   }
 
+  pub(crate) fn dcx(&self) -> DiagCtxtHandle<'psess> {
+    self.psess.dcx()
+  }
+
   pub(crate) fn step(&mut self) {
     let (token, spacing) = self.token_cursor.step();
     self.pos += 1;
@@ -51,7 +70,10 @@ impl<'psess> Parser<'psess> {
     self.token.kind == token_kind
   }
   pub(crate) fn check_kw(&self, kw: Symbol) -> bool {
-    self.token.is_kw(kw)
+    self.token_is_kw(self.token, kw)
+  }
+  pub(crate) fn token_is_kw(&self, token: Token, kw: Symbol) -> bool {
+    token.is_kw_in(kw, self.kw_script)
   }
 
   pub(crate) fn eat(&mut self, check: TokenKind) -> bool {
@@ -76,7 +98,7 @@ impl<'psess> Parser<'psess> {
       self.step();
       return Ok(self.token);
     } else {
-      todo!();
+      return Err(self.expected_err(format!("`{token_kind}`")));
     };
   }
   pub(crate) fn expect_kw(&mut self, kw: Symbol) -> PResult<'psess, Token> {
@@ -84,9 +106,19 @@ impl<'psess> Parser<'psess> {
       self.step();
       return Ok(self.prev_token);
     } else {
-      todo!()
+      return Err(self.expected_err(format!("`{}`", kw.kw_in(self.kw_script).as_str())));
     };
   }
+  /// "expected `)`, found `;`"
+  pub(crate) fn expected_err(&self, expected: String) -> Diag<'psess> {
+    return self
+      .dcx()
+      .struct_span_err(
+        self.token.span,
+        format!("expected {expected}, found `{}`", self.token.kind),
+      )
+      .with_label(self.token.span, format!("expected {expected}"));
+  }
 
   pub(crate) fn look_ahead(&self, n: usize) -> Token {
     let mut token_cursor = self.token_cursor.clone();
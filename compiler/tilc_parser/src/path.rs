@@ -1,4 +1,4 @@
-use tilc_ast::{BinOp, Delim, NodeIdx, Path, PathSegment, TokenKind, Use, UseKind};
+use tilc_ast::{BinOp, Delim, NodeIdx, Path, PathSegment, Spacing, TokenKind, Use, UseKind};
 use tilc_error::PResult;
 use tilc_span::{Ident, Span, kw};
 
@@ -98,7 +98,10 @@ impl<'a> Parser<'a> {
   }
   fn parse_path_segment_ident(&mut self) -> PResult<'a, Ident> {
     return match self.token.ident() {
-      Some((ident, false)) if ident.is_path_segment_ident() => Ok(ident),
+      Some((ident, false)) if ident.is_path_segment_ident(self.kw_script) => {
+        self.step();
+        Ok(ident)
+      }
       _ => self.parse_ident(),
     };
   }
@@ -106,10 +109,21 @@ impl<'a> Parser<'a> {
   fn parse_path_seq(&mut self) -> bool {
     if self.eat(TokenKind::Path) {
       return true;
-    } else if self.check(TokenKind::Colon) {
-      todo!("use double colon");
-    } else if self.check(TokenKind::Dot) {
-      todo!("use double colon instead of dot")
+    } else if self.check(TokenKind::Colon)
+      && self.token_spacing == Spacing::Sticked
+      && self.look_ahead_and(1, |t| t.ident().is_some())
+    {
+      // std:mem
+      //    ^
+      //    | token's position
+      let colon_span = self.token.span;
+      self
+        .dcx()
+        .struct_span_err(colon_span, "expected `::`, found `:`")
+        .with_suggestion(colon_span, "path segments are separated by `::`", "::")
+        .emit();
+      self.step();
+      return true;
     } else {
       return false;
     };
@@ -128,3 +142,28 @@ impl<'a> Parser<'a> {
     };
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use tilc_ast::{ExprKind, ItemKind, StatementKind};
+
+  use crate::tests_util::parse_items;
+
+  #[test]
+  fn single_colon() {
+    let (items, errors) = parse_items("fx f() { a:b(); }");
+    assert_eq!(errors, 1);
+    let ItemKind::Fn(function) = &items[0].kind else {
+      unreachable!();
+    };
+    let StatementKind::Semi(expr) = &function.block.as_ref().unwrap().statements[0].kind else {
+      unreachable!();
+    };
+    assert!(matches!(
+      &expr.kind,
+      ExprKind::Call(callee, _) if matches!(&callee.kind, ExprKind::Path(path) if path.segments.len() == 2)
+    ));
+
+    assert_eq!(parse_items("fx basty() { f(x: 1); }").1, 1);
+  }
+}
@@ -1,4 +1,7 @@
-use tilc_ast::{Mutability, NodeIdx, Pattern, PatternKind, TokenKind};
+use tilc_ast::{
+  AttributeStyle, BinOp, Delim, Expr, ExprKind, Mutability, NodeIdx, PatField, Pattern,
+  PatternKind, RangeLimits, TokenKind, UnOp,
+};
 use tilc_error::PResult;
 use tilc_span::{Span, kw};
 
@@ -8,16 +11,33 @@ impl<'a> Parser<'a> {
   pub(crate) fn parse_pattern(&mut self) -> PResult<'a, Box<Pattern>> {
     let lo = self.token.span;
 
-    let kind = if self.check_kw(kw::Mut) {
+    let kind = if self.eat_kw(kw::Underscore) {
+      PatternKind::Wild
+    } else if self.check_kw(kw::Mut) {
       self.expect_kw(kw::Mut)?;
       self.parse_pat_ident(Mutability::Mut)?
+    } else if self.check_pat_range_op() {
+      self.parse_pat_range(None)?
+    } else if self.check_pat_lit_start() {
+      let start = self.parse_pat_lit()?;
+      if self.check_pat_range_op() {
+        self.parse_pat_range(Some(start))?
+      } else {
+        PatternKind::Expr(start)
+      }
+    } else if self.check(TokenKind::OpenDelim(Delim::Paren)) {
+      self.parse_pat_tuple()?
+    } else if self.check(TokenKind::BinOp(BinOp::And)) || self.check(TokenKind::AndAnd) {
+      self.parse_pat_ref()?
+    } else if self.check_pat_path_start() {
+      self.parse_pat_path()?
     } else if self.token.ident().is_some() {
       self.parse_pat_ident(Mutability::Nope)?
     } else {
-      todo!();
+      return Err(self.expected_err(String::from("pattern")));
     };
 
-    return Ok(self.make_pat(kind, lo.to(self.token.span)));
+    return Ok(self.make_pat(kind, lo.to(self.prev_token.span)));
   }
 
   fn parse_pat_ident(&mut self, mutability: Mutability) -> PResult<'a, PatternKind> {
@@ -28,13 +48,197 @@ impl<'a> Parser<'a> {
     } else {
       None
     };
-    if pat.is_some() {
-      todo!();
-    };
 
     return Ok(PatternKind::Ident(mutability, ident, pat));
   }
 
+  /// `A::B`, `A(..)` or `A {..}`, a lone `A` is an ident pattern
+  fn check_pat_path_start(&self) -> bool {
+    return self.check_path_start()
+      && matches!(
+        self.look_ahead(1).kind,
+        TokenKind::Path | TokenKind::OpenDelim(Delim::Paren | Delim::Brace)
+      );
+  }
+  /// `A::B`, `Some(x)` or `A { x, y: 1, .. }`
+  fn parse_pat_path(&mut self) -> PResult<'a, PatternKind> {
+    let path = self.parse_path()?;
+
+    if self.check(TokenKind::OpenDelim(Delim::Paren)) {
+      let (pats, _) = self.parse_pat_paren_seq()?;
+      return Ok(PatternKind::TupleStruct(path, pats));
+    } else if self.check(TokenKind::OpenDelim(Delim::Brace)) {
+      let (fields, has_rest) = self.parse_pat_fields()?;
+      return Ok(PatternKind::Struct(path, fields, has_rest));
+    };
+
+    return Ok(PatternKind::Path(path));
+  }
+  /// `{ x, ayspaly y, #[attr] z: (1, _), .. }` and whether it ends with `..`
+  fn parse_pat_fields(&mut self) -> PResult<'a, (Vec<PatField>, bool)> {
+    self.expect(TokenKind::OpenDelim(Delim::Brace))?;
+
+    let mut fields = Vec::new();
+    let mut has_rest = false;
+    while !self.eat(TokenKind::CloseDelim(Delim::Brace)) {
+      if self.eat(TokenKind::DotDot) {
+        has_rest = true;
+        // `..` must be the last thing in the braces
+        self.expect(TokenKind::CloseDelim(Delim::Brace))?;
+        break;
+      };
+      fields.push(self.parse_pat_field()?);
+
+      if !self.eat(TokenKind::Comma) {
+        self.expect(TokenKind::CloseDelim(Delim::Brace))?;
+        break;
+      };
+    }
+
+    return Ok((fields, has_rest));
+  }
+  fn parse_pat_field(&mut self) -> PResult<'a, PatField> {
+    let attrs = self.parse_attributes(AttributeStyle::Outer)?;
+    let lo = self.token.span;
+
+    let is_shorthand = self.look_ahead(1).kind != TokenKind::Colon;
+    let (ident, pat) = if is_shorthand {
+      let mutability = if self.eat_kw(kw::Mut) {
+        Mutability::Mut
+      } else {
+        Mutability::Nope
+      };
+      let ident = self.parse_ident()?;
+      let kind = PatternKind::Ident(mutability, ident, None);
+      (ident, self.make_pat(kind, lo.to(self.prev_token.span)))
+    } else {
+      let ident = self.parse_ident()?;
+      self.expect(TokenKind::Colon)?;
+      (ident, self.parse_pattern()?)
+    };
+
+    return Ok(PatField {
+      idx: NodeIdx::DUMMY,
+
+      attrs,
+      ident,
+      pat,
+      is_shorthand,
+
+      span: lo.to(self.prev_token.span),
+    });
+  }
+
+  /// `(a, b)`, `(a,)`, `()` or the parenthesized `(a)`
+  fn parse_pat_tuple(&mut self) -> PResult<'a, PatternKind> {
+    let (mut pats, trailing_comma) = self.parse_pat_paren_seq()?;
+    if pats.len() == 1 && !trailing_comma {
+      return Ok(pats.remove(0).kind);
+    };
+    return Ok(PatternKind::Tuple(pats));
+  }
+  /// `(a, b)` or `(a, b,)` and whether it ends with a comma
+  fn parse_pat_paren_seq(&mut self) -> PResult<'a, (Vec<Pattern>, bool)> {
+    self.expect(TokenKind::OpenDelim(Delim::Paren))?;
+
+    let mut pats = Vec::new();
+    let mut trailing_comma = false;
+    while !self.eat(TokenKind::CloseDelim(Delim::Paren)) {
+      pats.push(*self.parse_pattern()?);
+
+      trailing_comma = self.eat(TokenKind::Comma);
+      if !trailing_comma {
+        self.expect(TokenKind::CloseDelim(Delim::Paren))?;
+        break;
+      };
+    }
+
+    return Ok((pats, trailing_comma));
+  }
+  /// `&a`, `&ayspaly a` or `&&a`
+  fn parse_pat_ref(&mut self) -> PResult<'a, PatternKind> {
+    let lo = self.token.span;
+    let is_double = self.check(TokenKind::AndAnd);
+    self.step();
+
+    let mutability = if self.eat_kw(kw::Mut) {
+      Mutability::Mut
+    } else {
+      Mutability::Nope
+    };
+    let kind = PatternKind::Ref(mutability, self.parse_pattern()?);
+    if !is_double {
+      return Ok(kind);
+    };
+
+    // `&&a` is `& &a`
+    let inner = self.make_pat(kind, lo.to(self.prev_token.span));
+    return Ok(PatternKind::Ref(Mutability::Nope, inner));
+  }
+
+  fn check_pat_lit_start(&self) -> bool {
+    return self.token.lit(self.kw_script).is_some()
+      || (self.check(TokenKind::BinOp(BinOp::Minus))
+        && self.look_ahead(1).lit(self.kw_script).is_some());
+  }
+  /// `1` or `-1`
+  fn parse_pat_lit(&mut self) -> PResult<'a, Box<Expr>> {
+    if !self.eat(TokenKind::BinOp(BinOp::Minus)) {
+      return self.parse_expr_lit(Vec::new());
+    };
+
+    let lo = self.prev_token.span;
+    let lit = self.parse_expr_lit(Vec::new())?;
+    let span = lo.to(lit.span);
+    return Ok(self.make_expr(Vec::new(), ExprKind::Unary(UnOp::Neg, lit), span));
+  }
+  fn check_pat_range_op(&self) -> bool {
+    return matches!(
+      self.token.kind,
+      TokenKind::DotDot | TokenKind::DotDotEq | TokenKind::DotDotDot
+    );
+  }
+  /// `..=b`, `a..=b`, `a..b` or `a..` from the operator
+  fn parse_pat_range(&mut self, start: Option<Box<Expr>>) -> PResult<'a, PatternKind> {
+    let op_span = self.token.span;
+    let limits = match self.token.kind {
+      TokenKind::DotDot => RangeLimits::HalfOpen,
+      TokenKind::DotDotEq => RangeLimits::Closed,
+
+      _ => {
+        self
+          .dcx()
+          .struct_span_err(op_span, "use `..=` for inclusive range patterns")
+          .with_suggestion(op_span, "replace `...` with `..=`", "..=")
+          .emit();
+        RangeLimits::Closed
+      }
+    };
+    self.step();
+
+    let end = if self.check_pat_lit_start() {
+      Some(self.parse_pat_lit()?)
+    } else {
+      None
+    };
+    if end.is_none() && limits == RangeLimits::Closed {
+      self
+        .dcx()
+        .struct_span_err(op_span, "inclusive range with no end")
+        .with_label(op_span, "expected the end of the range")
+        .with_note("inclusive ranges must be bounded at the end (`..=b` or `a..=b`)")
+        .emit();
+    } else if end.is_none() && start.is_none() {
+      self
+        .dcx()
+        .struct_span_err(op_span, "range pattern with no bounds")
+        .with_label(op_span, "expected a literal before or after `..`")
+        .emit();
+    };
+
+    return Ok(PatternKind::Range(start, end, limits));
+  }
+
   fn make_pat(&self, kind: PatternKind, span: Span) -> Box<Pattern> {
     return Box::new(Pattern {
       idx: NodeIdx::DUMMY,
@@ -43,3 +247,100 @@ impl<'a> Parser<'a> {
     });
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use tilc_ast::{ExprKind, ItemKind, Mutability, PatternKind, RangeLimits, StatementKind};
+
+  use crate::tests_util::{parse_expr, parse_items};
+
+  #[test]
+  fn path_patterns() {
+    let (items, errors) = parse_items(
+      "fx f() {
+        saikestir a {
+          Some(x) => 1,
+          A::B => 2,
+          ozi::C(ayspaly x, _,) => 3,
+          A { x, ayspaly y, z: (1, _), .. } => 4,
+          Ozi {} => 5,
+          x => 6,
+          ayspaly n @ Some(_) => 7,
+        };
+      }",
+    );
+    assert_eq!(errors, 0);
+    let ItemKind::Fn(function) = &items[0].kind else {
+      unreachable!();
+    };
+    let StatementKind::Semi(expr) = &function.block.as_ref().unwrap().statements[0].kind else {
+      unreachable!();
+    };
+    let ExprKind::Match(_, arms) = &expr.kind else {
+      unreachable!();
+    };
+
+    assert!(matches!(
+      &arms[0].pat.kind,
+      PatternKind::TupleStruct(path, pats) if path.segments.len() == 1 && pats.len() == 1
+    ));
+    assert!(matches!(&arms[1].pat.kind, PatternKind::Path(path) if path.segments.len() == 2));
+    assert!(matches!(
+      &arms[2].pat.kind,
+      PatternKind::TupleStruct(path, pats) if path.segments.len() == 2 && pats.len() == 2
+    ));
+    let PatternKind::Struct(_, fields, true) = &arms[3].pat.kind else {
+      panic!("expected a struct pattern with `..`");
+    };
+    let shorthands = fields.iter().map(|field| field.is_shorthand);
+    assert_eq!(shorthands.collect::<Vec<_>>(), [true, true, false]);
+    assert!(
+      matches!(&arms[4].pat.kind, PatternKind::Struct(_, fields, false) if fields.is_empty())
+    );
+    assert!(matches!(arms[5].pat.kind, PatternKind::Ident(_, _, None)));
+    assert!(matches!(
+      &arms[6].pat.kind,
+      PatternKind::Ident(Mutability::Mut, _, Some(pat)) if matches!(pat.kind, PatternKind::TupleStruct(..))
+    ));
+
+    assert_eq!(
+      parse_items("fx f() { saikestir a { A { .., x } => 1 } }").1,
+      1
+    );
+  }
+
+  #[test]
+  fn range_patterns() {
+    let (items, errors) =
+      parse_items("fx f() { saikestir a { -5..=-1 => 0, 0..10 => 1, 10.. => 2, ..=0 => 3 }; }");
+    assert_eq!(errors, 0);
+    let ItemKind::Fn(function) = &items[0].kind else {
+      unreachable!();
+    };
+    let StatementKind::Semi(expr) = &function.block.as_ref().unwrap().statements[0].kind else {
+      unreachable!();
+    };
+    let ExprKind::Match(_, arms) = &expr.kind else {
+      unreachable!();
+    };
+    let ranges = arms.iter().map(|arm| match &arm.pat.kind {
+      PatternKind::Range(start, end, limits) => (start.is_some(), end.is_some(), *limits),
+      _ => unreachable!(),
+    });
+    assert_eq!(
+      ranges.collect::<Vec<_>>(),
+      [
+        (true, true, RangeLimits::Closed),
+        (true, true, RangeLimits::HalfOpen),
+        (true, false, RangeLimits::HalfOpen),
+        (false, true, RangeLimits::Closed),
+      ]
+    );
+    assert_eq!(parse_expr("saikestir a { 1...2 => 0 }").1, 1);
+    assert_eq!(parse_expr("saikestir a { 1..= => 0 }").1, 1);
+    assert_eq!(
+      parse_expr("saikestir x { n @ 1..=5 => {} _ => {} }"),
+      (String::from("saikestir x {2}"), 0)
+    );
+  }
+}
@@ -1,5 +1,6 @@
 use tilc_ast::{
-  Attribute, AttributeStyle, Expr, Local, LocalKind, NodeIdx, Statement, StatementKind, TokenKind,
+  Attribute, AttributeStyle, Block, Delim, Expr, Local, LocalKind, NodeIdx, Safety, Statement,
+  StatementKind, TokenKind,
 };
 use tilc_error::PResult;
 use tilc_span::{Span, kw};
@@ -11,7 +12,7 @@ impl<'a> Parser<'a> {
     let mut outer_attrs = self.parse_attributes(AttributeStyle::Outer)?;
     let lo = self.token.span;
 
-    let mut stmt = if self.token.is_kw(kw::Let) {
+    let mut stmt = if self.check_kw(kw::Let) {
       self.expect_kw(kw::Let)?;
       let local = self.parse_local(outer_attrs.clone())?;
 
@@ -28,7 +29,7 @@ impl<'a> Parser<'a> {
     } else if self.eat(TokenKind::Semicolon) {
       return Ok(None);
     } else {
-      let expr = self.parse_expr()?;
+      let expr = self.parse_expr_stmt()?;
       let with_semi = self.eat(TokenKind::Semicolon);
       let span = lo.to(expr.span);
       self.make_stmt(
@@ -96,6 +97,42 @@ impl<'a> Parser<'a> {
       span: lo.to(hi),
     }));
   }
+  /// `{ $stmt* }`, only the last expression can omit its `;` unless it is block-like
+  pub(crate) fn parse_block(&mut self, safety: Safety) -> PResult<'a, Box<Block>> {
+    let lo = self.token.span;
+    self.expect(TokenKind::OpenDelim(Delim::Brace))?;
+
+    let mut statements = Vec::new();
+    while !self.eat(TokenKind::CloseDelim(Delim::Brace)) {
+      if self.check(TokenKind::Eof) {
+        break;
+      };
+
+      // Repetitive `;` are skipped
+      let Some(statement) = self.parse_statement()? else {
+        continue;
+      };
+      if let StatementKind::Expr(expr) = &statement.kind {
+        let is_tail = self.check(TokenKind::CloseDelim(Delim::Brace));
+        if !is_tail && !expr.kind.is_block_like() {
+          let semi_span = self.prev_token.span.shrink_to_hi();
+          self
+            .expected_err(String::from("`;`"))
+            .with_suggestion(semi_span, "add `;` here", ";")
+            .emit();
+        };
+      };
+      statements.push(statement);
+    }
+
+    return Ok(Box::new(Block {
+      idx: NodeIdx::DUMMY,
+
+      statements,
+      safety,
+      span: lo.to(self.prev_token.span),
+    }));
+  }
   fn make_stmt(&self, kind: StatementKind, span: Span) -> Statement {
     return Statement {
       idx: NodeIdx::DUMMY,
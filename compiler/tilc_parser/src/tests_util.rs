@@ -0,0 +1,158 @@
+use tilc_ast::{
+  CaptureBy, Expr, ExprKind, Item, ItemKind, NodeIdx, RangeLimits, Sandyq, StatementKind, TyKind,
+  UnOp,
+};
+use tilc_parse::{file_keyword_script, lex_token_stream};
+use tilc_session::ParseSession;
+use tilc_span::{BytePos, Pos, Span, with_session_globals};
+
+use crate::Parser;
+
+/// Session over the source map of the session globals
+pub(crate) fn new_psess() -> ParseSession {
+  let source_map = with_session_globals(|session_globals| session_globals.source_map());
+  return ParseSession::new(source_map);
+}
+/// Lexes and parses `src` as a whole sandyq, the errors stay in `psess`
+///
+/// A sandyq that fails to parse is reported and comes back empty
+pub(crate) fn parse_sandyq(psess: &ParseSession, src: &str) -> Sandyq {
+  let kw_script = file_keyword_script(src, psess.edition());
+  let stream = lex_token_stream(psess, src, BytePos::from_u32(0), kw_script).unwrap();
+  return match Parser::new(psess, stream, kw_script).parse_sandyq() {
+    Ok(sandyq) => sandyq,
+    Err(diag) => {
+      diag.emit();
+      Sandyq {
+        idx: NodeIdx::DUMMY,
+
+        attrs: Vec::new(),
+        items: Vec::new(),
+
+        span: Span::EMPTY,
+      }
+    }
+  };
+}
+/// Parenthesized form of the expression statement in `fx f() { <src>; }`
+pub(crate) fn parse_expr(src: &str) -> (String, usize) {
+  let psess = new_psess();
+  let sandyq = parse_sandyq(&psess, &format!("fx f() {{ {src}; }}"));
+
+  let ItemKind::Fn(function) = &sandyq.items[0].kind else {
+    unreachable!();
+  };
+  let StatementKind::Semi(expr) = &function.block.as_ref().unwrap().statements[0].kind else {
+    unreachable!();
+  };
+  return (render(expr), psess.dcx().err_count());
+}
+/// Items of `src` and the number of reported errors
+pub(crate) fn parse_items(src: &str) -> (Vec<Item>, usize) {
+  let psess = new_psess();
+  let sandyq = parse_sandyq(&psess, src);
+  return (sandyq.items, psess.dcx().err_count());
+}
+pub(crate) fn render(expr: &Expr) -> String {
+  match &expr.kind {
+    ExprKind::Binary(op, lhs, rhs) => {
+      format!("({} {} {})", render(lhs), op.node.as_str(), render(rhs))
+    }
+    ExprKind::Assign(lhs, _, rhs) => format!("({} = {})", render(lhs), render(rhs)),
+    ExprKind::AssignOp(_, lhs, rhs) => format!("({} op= {})", render(lhs), render(rhs)),
+    ExprKind::Unary(op, expr) => {
+      let op = match op {
+        UnOp::Deref => "*",
+        UnOp::Not => "!",
+        UnOp::Neg => "-",
+      };
+      format!("({op}{})", render(expr))
+    }
+    ExprKind::AddrOf(_, expr) => format!("(&{})", render(expr)),
+    ExprKind::Cast(expr, ty) => match &ty.kind {
+      TyKind::FnPtr(fn_ptr) => {
+        format!(
+          "({} sekildi fx({}))",
+          render(expr),
+          fn_ptr.decl.params.len()
+        )
+      }
+      _ => format!("({} sekildi _)", render(expr)),
+    },
+    ExprKind::Paren(expr) => render(expr),
+    ExprKind::Tuple(exprs) => {
+      let exprs = exprs.iter().map(render).collect::<Vec<_>>();
+      format!("({},)", exprs.join(", "))
+    }
+    ExprKind::Lit(lit) => lit.to_string(),
+    ExprKind::Path(path) => path.segments[0].ident.name.as_str().to_owned(),
+    ExprKind::Let(..) => String::from("ainymaly"),
+    ExprKind::Call(callee, args) => {
+      let args = args.iter().map(render).collect::<Vec<_>>();
+      format!("{}({})", render(callee), args.join(", "))
+    }
+    ExprKind::MethodCall(method_call) => {
+      let args = method_call.args.iter().map(render);
+      format!(
+        "{}.{}({})",
+        render(&method_call.receiver),
+        method_call.seg.ident.name.as_str(),
+        args.collect::<Vec<_>>().join(", ")
+      )
+    }
+    ExprKind::Field(expr, ident) => format!("({}.{})", render(expr), ident.name.as_str()),
+    ExprKind::Index(expr, index, _) => format!("{}[{}]", render(expr), render(index)),
+    ExprKind::Try(expr) => format!("{}?", render(expr)),
+    ExprKind::Block(block, _) => format!("{{{}}}", block.statements.len()),
+    ExprKind::If(cond, block, else_expr) => {
+      let else_expr = else_expr
+        .as_ref()
+        .map(|expr| format!(" bolmasa {}", render(expr)));
+      format!(
+        "eger {} {{{}}}{}",
+        render(cond),
+        block.statements.len(),
+        else_expr.unwrap_or_default()
+      )
+    }
+    ExprKind::Match(expr, arms) => format!("saikestir {} {{{}}}", render(expr), arms.len()),
+    ExprKind::ForLoop(_, iter, ..) => format!("ushin _ ishinde {}", render(iter)),
+    ExprKind::While(cond, ..) => format!("azirshe {}", render(cond)),
+    ExprKind::Loop(..) => String::from("qaitala"),
+    ExprKind::Break(label, expr) => {
+      let label = label.map(|label| format!(" {}", label.ident.name.as_str()));
+      let expr = expr.as_ref().map(|expr| format!(" {}", render(expr)));
+      format!(
+        "toqta{}{}",
+        label.unwrap_or_default(),
+        expr.unwrap_or_default()
+      )
+    }
+    ExprKind::Continue(_) => String::from("jalgastyr"),
+    ExprKind::Range(start, end, limits) => {
+      let op = match limits {
+        RangeLimits::HalfOpen => "..",
+        RangeLimits::Closed => "..=",
+      };
+      let start = start.as_ref().map(|start| render(start));
+      let end = end.as_ref().map(|end| render(end));
+      format!(
+        "({}{op}{})",
+        start.unwrap_or_default(),
+        end.unwrap_or_default()
+      )
+    }
+    ExprKind::Closure(closure) => {
+      let capture = match closure.capture_by {
+        CaptureBy::Value { .. } => "kosh ",
+        CaptureBy::Ref => "",
+      };
+      let params = closure.fn_decl.params.len();
+      format!("{capture}|{params}| {}", render(&closure.body))
+    }
+    ExprKind::Ret(expr) => {
+      let expr = expr.as_ref().map(|expr| format!(" {}", render(expr)));
+      format!("qaitar{}", expr.unwrap_or_default())
+    }
+  }
+}
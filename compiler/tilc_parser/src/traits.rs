@@ -0,0 +1,238 @@
+use tilc_ast::{
+  AssociatedItemKind, AttributeStyle, Const, Defaultness, Delim, Impl, ImplKind, Item, ItemKind,
+  NodeIdx, Safety, TokenKind, Trait, Ty, TyAlias, TyKind, Vis,
+};
+use tilc_error::PResult;
+use tilc_span::{Ident, Span, Symbol, kw};
+
+use crate::{ItemInfo, Parser};
+
+impl<'a> Parser<'a> {
+  /// `kw` or `qauipti kw`
+  pub(crate) fn check_maybe_unsafe_kw(&self, kw: Symbol) -> bool {
+    self.check_kw(kw) || (self.check_kw(kw::Unsafe) && self.token_is_kw(self.look_ahead(1), kw))
+  }
+  pub(crate) fn parse_safety(&mut self) -> Safety {
+    if self.eat_kw(kw::Unsafe) {
+      return Safety::Unsafe(self.prev_token.span);
+    };
+
+    return Safety::Inherit;
+  }
+
+  /// `qauipti qasiet A<T>: B + 'a { ... }`
+  pub(crate) fn parse_trait_item(&mut self, start_span: Span, vis: &Vis) -> PResult<'a, ItemInfo> {
+    let safety = self.parse_safety();
+    self.expect_kw(kw::Trait)?;
+    let ident = self.parse_ident()?;
+    let generics = self.parse_generics()?;
+    let bounds = if self.eat(TokenKind::Colon) {
+      self.parse_generic_bounds()?
+    } else {
+      Vec::new()
+    };
+    let items = self.parse_associated_items()?;
+
+    return Ok((
+      ident,
+      ItemKind::Trait(Box::new(Trait {
+        idx: NodeIdx::DUMMY,
+
+        vis: vis.clone(),
+        safety,
+        ident,
+        generics,
+        bounds,
+        items,
+
+        span: start_span.to(self.prev_token.span),
+      })),
+    ));
+  }
+  /// `asyru<T> A { ... }`, `qauipti asyru B ushin A {}` or `asyru !B ushin A {}`
+  pub(crate) fn parse_impl_item(&mut self, start_span: Span) -> PResult<'a, ItemInfo> {
+    let safety = self.parse_safety();
+    self.expect_kw(kw::Impl)?;
+    let generics = self.parse_generics()?;
+
+    let kind = if self.eat(TokenKind::Bang) {
+      ImplKind::Negative(self.prev_token.span)
+    } else {
+      ImplKind::Positive
+    };
+    let ty = self.parse_ty()?;
+
+    let (of_trait, self_ty) = if self.eat_kw(kw::For) {
+      let Ty {
+        kind: TyKind::Path(path),
+        ..
+      } = *ty
+      else {
+        return Err(
+          self
+            .dcx()
+            .struct_span_err(ty.span, "expected a trait, found type")
+            .with_label(ty.span, "not a trait"),
+        );
+      };
+      (Some(path), self.parse_ty()?)
+    } else {
+      if let ImplKind::Negative(span) = kind {
+        self
+          .dcx()
+          .struct_span_err(span, "inherent impls cannot be negative")
+          .with_label(span, "negative because of this")
+          .emit();
+      };
+      (None, ty)
+    };
+    let items = self.parse_associated_items()?;
+
+    return Ok((
+      Ident::DUMMY,
+      ItemKind::Impl(Box::new(Impl {
+        safety,
+        generics,
+        kind,
+        of_trait,
+        self_ty,
+        items,
+
+        defaultness: Defaultness::Default,
+        span: start_span.to(self.prev_token.span),
+      })),
+    ));
+  }
+
+  fn parse_associated_items(&mut self) -> PResult<'a, Vec<Item<AssociatedItemKind>>> {
+    self.expect(TokenKind::OpenDelim(Delim::Brace))?;
+
+    let mut items = Vec::new();
+    while !self.eat(TokenKind::CloseDelim(Delim::Brace)) {
+      items.push(self.parse_associated_item()?);
+    }
+
+    return Ok(items);
+  }
+  /// `fx f() {}`, `turaqty N: b32 = 1;` or `tur T: Bound = b32;` (values and bodies are optional)
+  fn parse_associated_item(&mut self) -> PResult<'a, Item<AssociatedItemKind>> {
+    let attrs = self.parse_attributes(AttributeStyle::Outer)?;
+    let lo = self.token.span;
+    let vis = self.parse_vis(false)?;
+
+    let (ident, kind) = if self.check_for_fn_item() {
+      let (ident, ItemKind::Fn(function)) = self.parse_fn_item(lo)? else {
+        unreachable!();
+      };
+      (ident, AssociatedItemKind::Fn(function))
+    } else if self.eat_kw(kw::Const) {
+      let ident = self.parse_ident()?;
+      let generics = self.parse_generics()?;
+      self.expect(TokenKind::Colon)?;
+      let ty = self.parse_ty()?;
+      let expr = if self.eat(TokenKind::Eq) {
+        Some(self.parse_expr()?)
+      } else {
+        None
+      };
+      self.expect(TokenKind::Semicolon)?;
+
+      let const_item = Const {
+        idx: NodeIdx::DUMMY,
+
+        attrs: attrs.clone(),
+        vis: vis.clone(),
+        ident,
+        generics,
+        ty,
+        expr,
+
+        defaultness: Defaultness::Default,
+      };
+      (ident, AssociatedItemKind::Const(Box::new(const_item)))
+    } else if self.eat_kw(kw::Type) {
+      let ident = self.parse_ident()?;
+      let generics = self.parse_generics()?;
+      let bounds = if self.eat(TokenKind::Colon) {
+        self.parse_generic_bounds()?
+      } else {
+        Vec::new()
+      };
+      let ty = if self.eat(TokenKind::Eq) {
+        Some(self.parse_ty()?)
+      } else {
+        None
+      };
+      self.expect(TokenKind::Semicolon)?;
+
+      let alias = TyAlias {
+        vis: vis.clone(),
+        ident,
+        generics,
+        bounds,
+        ty,
+
+        defaultness: Defaultness::Default,
+      };
+      (ident, AssociatedItemKind::TyAlias(Box::new(alias)))
+    } else {
+      let expected = format!(
+        "`{}`, `{}` or `{}`",
+        kw::Function.kw_in(self.kw_script).as_str(),
+        kw::Const.kw_in(self.kw_script).as_str(),
+        kw::Type.kw_in(self.kw_script).as_str(),
+      );
+      return Err(self.expected_err(expected));
+    };
+
+    return Ok(Item {
+      idx: NodeIdx::DUMMY,
+
+      attrs,
+      vis,
+      kind,
+      ident,
+
+      span: lo.to(self.prev_token.span),
+    });
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use tilc_ast::{ImplKind, ItemKind, Safety, VisKind};
+
+  use crate::tests_util::parse_items;
+
+  #[test]
+  fn traits_and_impls() {
+    let (items, errors) = parse_items(
+      "qauipti qasiet A<T>: B + 'a {
+        turaqty N: b32;
+        tur Item: B = b32;
+        fx f();
+        fx g() { 1 }
+      }
+      asyru<T> C {
+        barsha turaqty N: b32 = 1;
+      }
+      qauipti asyru A ushin C {}
+      asyru !B ushin C {}",
+    );
+    assert_eq!(errors, 0);
+    let ItemKind::Trait(trait_item) = &items[0].kind else {
+      unreachable!();
+    };
+    assert!(matches!(trait_item.safety, Safety::Unsafe(_)));
+    assert_eq!((trait_item.bounds.len(), trait_item.items.len()), (2, 4));
+    let ItemKind::Impl(impl_item) = &items[1].kind else {
+      unreachable!();
+    };
+    assert!(impl_item.of_trait.is_none());
+    assert!(matches!(impl_item.items[0].vis.kind, VisKind::Public));
+    assert!(matches!(&items[2].kind, ItemKind::Impl(i) if i.of_trait.is_some()));
+    assert!(matches!(&items[3].kind, ItemKind::Impl(i) if matches!(i.kind, ImplKind::Negative(_))));
+
+    assert_eq!(parse_items("asyru !C {}").1, 1);
+  }
+}
@@ -3,6 +3,7 @@ use std::rc::Rc;
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 use tilc_ast::{AttrIdx, AttrIdxGen};
+use tilc_error::{DiagCtxt, DiagCtxtHandle};
 use tilc_span::{Edition, SourceMap, Span, Symbol};
 
 #[derive(Debug)]
@@ -11,24 +12,43 @@ pub struct ParseSession {
   pub symbol_repo: SymbolRepo,
 
   source_map: Rc<SourceMap>,
+  dcx: DiagCtxt,
 
   attr_idx_gen: AttrIdxGen,
 }
 impl ParseSession {
   pub fn new(source_map: Rc<SourceMap>) -> Self {
+    Self::with_edition(source_map, Default::default())
+  }
+  pub fn with_edition(source_map: Rc<SourceMap>, edition: Edition) -> Self {
     Self {
-      edition: Default::default(),
+      edition,
       symbol_repo: SymbolRepo(Default::default()),
 
       source_map,
+      dcx: DiagCtxt::new(),
 
       attr_idx_gen: AttrIdxGen::new(),
     }
   }
 
+  /// Session with the same source map and edition, whose diagnostics aren't printed
+  pub fn scratch(&self) -> Self {
+    Self {
+      dcx: DiagCtxt::silent(),
+      ..Self::with_edition(self.source_map(), self.edition)
+    }
+  }
+
+  pub fn edition(&self) -> Edition {
+    self.edition
+  }
   pub fn source_map(&self) -> Rc<SourceMap> {
     self.source_map.clone()
   }
+  pub fn dcx(&self) -> DiagCtxtHandle<'_> {
+    self.dcx.handle()
+  }
   pub fn make_attr_idx(&self) -> AttrIdx {
     return self.attr_idx_gen.make_attr_idx();
   }
@@ -41,4 +61,10 @@ impl SymbolRepo {
     debug_assert!(!self.0.is_locked());
     self.0.write().entry(symbol).or_insert_with(|| span);
   }
+  /// Calls `f` for every symbol with the span of its first occurrence
+  pub fn for_each<F: FnMut(Symbol, Span)>(&self, mut f: F) {
+    for (symbol, span) in self.0.read().iter() {
+      f(*symbol, *span);
+    }
+  }
 }
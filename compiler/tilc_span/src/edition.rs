@@ -4,4 +4,28 @@
 pub enum Edition {
   #[default]
   Edition20xx,
+  /// [`Edition::Edition20xx`] with keywords spelled in Cyrillic
+  Edition20xxCyrillic,
+}
+impl Edition {
+  pub const fn keyword_script(self) -> KeywordScript {
+    match self {
+      Self::Edition20xx => KeywordScript::Latin,
+      Self::Edition20xxCyrillic => KeywordScript::Cyrillic,
+    }
+  }
+}
+
+/// Script in which keywords are spelled
+///
+/// Only the spellings of the chosen script are reserved,
+/// spellings of the other script are ordinary identifiers
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq)]
+pub enum KeywordScript {
+  /// ainymaly, eger, bolmasa
+  Latin,
+  /// айнымалы, егер, болмаса
+  Cyrillic,
 }
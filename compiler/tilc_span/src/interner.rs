@@ -25,5 +25,14 @@ impl Interner {
     debug_assert!(is_new);
     Symbol::new(idx as u32)
   }
+  pub(crate) fn get(&self, symbol: Symbol) -> &'static str {
+    self
+      .0
+      .read()
+      .0
+      .get_index(symbol.idx())
+      .copied()
+      .unwrap_or_else(|| unreachable!("symbol was not interned"))
+  }
 }
 struct InnerInterner(IndexSet<&'static str>);
@@ -3,41 +3,42 @@ use tilc_macros::symbols;
 symbols! {
   Keywords {
     // declarations & bindings
-    Let: "ainymaly",
-    Const: "turaqty",
+    Let: "ainymaly" | "айнымалы",
+    Const: "turaqty" | "тұрақты",
     // Static: "",
 
     // types & related modifiers
-    Struct: "qurylym",
-    Enum: "tizbe",
-    // Union: "",
-    SelfType: "Ozi",
-    Trait: "qasiet",
-    Type: "tur",
+    Struct: "qurylym" | "құрылым",
+    Enum: "tizbe" | "тізбе",
+    Union: "birlestik" | "бірлестік",
+    SelfType: "Ozi" | "Өзі",
+    Trait: "qasiet" | "қасиет",
+    Type: "tur" | "түр",
     // Dyn: "",
 
     // control flow
-    If: "eger",
-    Else: "bolmasa",
-    Match: "saikestir",
-    // Loop: "",
-    // While: "",
-    For: "ushin",
-    // Break: "",
-    // Continue: "",
-    Return: "qaitar",
+    If: "eger" | "егер",
+    Else: "bolmasa" | "болмаса",
+    Match: "saikestir" | "сәйкестір",
+    Loop: "qaitala" | "қайтала",
+    While: "azirshe" | "әзірше",
+    For: "ushin" | "үшін",
+    In: "ishinde" | "ішінде",
+    Break: "toqta" | "тоқта",
+    Continue: "jalgastyr" | "жалғастыр",
+    Return: "qaitar" | "қайтар",
 
     // functions & modules
-    Function: "fx",
-    Mod: "korpe",
-    Use: "ash",
-    Sandyq: "sandyq",
-    Super: "super",
-    SelfValue: "ozi",
-    Impl: "asyru",
+    Function: "fx" | "фх",
+    Mod: "korpe" | "көрпе",
+    Use: "ash" | "аш",
+    Sandyq: "sandyq" | "сандық",
+    Super: "super" | "супер",
+    SelfValue: "ozi" | "өзі",
+    Impl: "asyru" | "асыру",
 
     // visibility & access control
-    Pub: "barsha",
+    Pub: "barsha" | "барша",
     // Priv: "",
 
     // async & coroutines
@@ -48,14 +49,39 @@ symbols! {
     // meta programming
     // Macro: "",
 
+    // literals
+    True: "ras" | "рас",
+    False: "jalgan" | "жалған",
+
     // other
     Underscore: "_",
-    As: "sekildi",
-    Mut: "ayspaly",
+    As: "sekildi" | "секілді",
+    Unsafe: "qauipti" | "қауіпті",
+    Move: "kosh" | "көш",
+    Mut: "ayspaly" | "айспалы",
   }
 
   Symbols {
     Main: "basty",
+    KeywordScript: "kilt_sozder",
+    Latin: "latyn",
+    Cyrillic: "kirill",
+    Doc: "doc",
+    Path: "path",
+
+    // primitive types
+    b8: "b8",
+    b16: "b16",
+    b32: "b32",
+    b64: "b64",
+    b128: "b128",
+    n8: "n8",
+    n16: "n16",
+    n32: "n32",
+    n64: "n64",
+    n128: "n128",
+    q32: "q32",
+    q64: "q64",
 
     dummy: "<dummy>"
   }
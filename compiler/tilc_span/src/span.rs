@@ -41,6 +41,13 @@ impl Span {
     }
   }
 
+  pub fn lo(self) -> BytePos {
+    self.data().lo
+  }
+  pub fn hi(self) -> BytePos {
+    self.data().hi
+  }
+
   fn data(self) -> SpanData {
     let hi = self.lo_or_idx.saturating_add(self.len as u32);
     SpanData {
@@ -65,10 +72,10 @@ impl Span {
 
     Self::new(self_data.lo, end_data.hi, self_data.ctxt, parent)
   }
-  fn with_lo(self, lo: BytePos) -> Self {
+  pub fn with_lo(self, lo: BytePos) -> Self {
     self.data().with_lo(lo)
   }
-  fn with_hi(self, hi: BytePos) -> Self {
+  pub fn with_hi(self, hi: BytePos) -> Self {
     self.data().with_hi(hi)
   }
 
@@ -113,3 +120,12 @@ impl SpanData {
     Span::new(self.lo, hi, self.ctxt, self.parent)
   }
 }
+
+/// Node that carries its own span, e.g. the operator of a binary expression
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub struct Spanned<T> {
+  pub node: T,
+  pub span: Span,
+}
@@ -1,6 +1,6 @@
 use tilc_macros::uidx;
 
-use crate::{Span, kw, sym, with_session_globals};
+use crate::{CYRILLIC_KEYWORDS, KeywordScript, Span, kw, sym, with_session_globals};
 
 uidx! {
   struct SymbolIdx {}
@@ -23,9 +23,50 @@ impl Symbol {
   pub fn intern(str: &str) -> Self {
     with_session_globals(|session_globals| session_globals.symbol_interner.intern(str))
   }
+  pub fn as_str(&self) -> &'static str {
+    with_session_globals(|session_globals| session_globals.symbol_interner.get(*self))
+  }
+
+  fn is_reserved(self, script: KeywordScript) -> bool {
+    let is_latin_kw = self >= kw::Let && self <= kw::Mut;
+    match script {
+      KeywordScript::Latin => is_latin_kw,
+      KeywordScript::Cyrillic => {
+        CYRILLIC_KEYWORDS
+          .iter()
+          .any(|&(_, cyrillic)| cyrillic == self)
+          || (is_latin_kw && self.kw_in(script) == self)
+      }
+    }
+  }
 
-  fn is_reserved(self) -> bool {
-    self >= kw::Let && self <= kw::Mut
+  /// Spelling of the keyword in the given script
+  ///
+  /// Keywords that are spelled the same in both scripts (like `_`) are returned as is
+  pub fn kw_in(self, script: KeywordScript) -> Self {
+    match script {
+      KeywordScript::Latin => self,
+      KeywordScript::Cyrillic => CYRILLIC_KEYWORDS
+        .iter()
+        .find(|&&(latin, _)| latin == self)
+        .map_or(self, |&(_, cyrillic)| cyrillic),
+    }
+  }
+  /// Latin keyword for the spelling in the given script
+  pub fn kw_from(self, script: KeywordScript) -> Option<Self> {
+    if !self.is_reserved(script) {
+      return None;
+    };
+
+    match script {
+      KeywordScript::Latin => Some(self),
+      KeywordScript::Cyrillic => Some(
+        CYRILLIC_KEYWORDS
+          .iter()
+          .find(|&&(_, cyrillic)| cyrillic == self)
+          .map_or(self, |&(latin, _)| latin),
+      ),
+    }
   }
 }
 
@@ -41,10 +82,20 @@ impl Ident {
     span: Span::EMPTY,
   };
 
-  pub fn is_reserved(&self) -> bool {
-    self.name.is_reserved()
+  pub fn is_reserved(&self, script: KeywordScript) -> bool {
+    self.name.is_reserved(script)
+  }
+  /// `sandyq`, `super`, `ozi`, `Ozi` and `_` cannot be written as raw identifiers
+  pub fn can_be_raw(&self, script: KeywordScript) -> bool {
+    return !matches!(
+      self.name.kw_from(script),
+      Some(kw::Sandyq | kw::Super | kw::SelfValue | kw::SelfType | kw::Underscore)
+    );
   }
-  pub fn is_path_segment_ident(&self) -> bool {
-    return matches!(self.name, kw::Sandyq | kw::Super | kw::SelfValue);
+  pub fn is_path_segment_ident(&self, script: KeywordScript) -> bool {
+    return matches!(
+      self.name.kw_from(script),
+      Some(kw::Sandyq | kw::Super | kw::SelfValue | kw::SelfType)
+    );
   }
 }